@@ -1,5 +1,8 @@
 pub mod nav;
 pub mod protected_route;
+pub mod session_expired_modal;
 pub mod language_switcher;
 pub mod gauge;
+pub mod status_badge;
+pub mod time_series_chart;
 pub mod footer;
\ No newline at end of file
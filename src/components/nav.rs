@@ -1,5 +1,6 @@
 use crate::components::language_switcher::LanguageSwitcher;
-use crate::contexts::user_context::use_user;
+use crate::contexts::user_context::{use_user, UserAction};
+use crate::models::user::Role;
 use crate::router::AppRoute;
 use crate::services::auth_service;
 use i18nrs::yew::use_translation;
@@ -26,7 +27,7 @@ pub fn nav() -> Html
             {
                 if auth_service::logout().await.is_ok() 
                 {
-                    user_context.dispatch(None);
+                    user_context.dispatch(UserAction::Set(None));
                     navigator.push(&AppRoute::Home);
                 } 
                 else 
@@ -70,7 +71,7 @@ pub fn nav() -> Html
                             html! 
                             {
                                 <>
-                                    if user.is_admin 
+                                    if user.has_role(Role::Admin) 
                                     {
                                         <li><Link<AppRoute> to={AppRoute::Admin}>{ i18n.t("nav.admin") }</Link<AppRoute>></li>
                                     }
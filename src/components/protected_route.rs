@@ -1,4 +1,4 @@
-use crate::{contexts::user_context::use_user, router::AppRoute};
+use crate::{contexts::user_context::use_user, models::user::Role, pages::forbidden::Forbidden, router::AppRoute};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -6,6 +6,9 @@ use yew_router::prelude::*;
 pub struct ProtectedRouteProps 
 {
     pub children: Children,
+    /// Logged-in users without this role get a 403 page instead.
+    #[prop_or_default]
+    pub required_role: Role,
 }
 
 #[function_component(ProtectedRoute)]
@@ -13,18 +16,36 @@ pub fn protected_route(props: &ProtectedRouteProps) -> Html
 {
     let user_context = use_user();
     let navigator = use_navigator().unwrap();
+    let location = use_location().unwrap();
 
     if user_context.loading 
     {
         html! { <div>{"Loading..."}</div> }
     } 
-    else if user_context.user.is_some() 
+    else if let Some(user) = &user_context.user 
     {
-        html! { for props.children.iter() }
+        if user.has_role(props.required_role) 
+        {
+            html! { for props.children.iter() }
+        } 
+        else 
+        {
+            html! { <Forbidden /> }
+        }
+    } 
+    else if user_context.session_expired.is_some() 
+    {
+        // The session-expired modal offers to log in again from this page
+        html! {}
     } 
     else 
     {
-        navigator.push(&AppRoute::Home);
+        // Remember where the user was headed so login can bring them back
+        let return_to = format!("{}{}", location.path(), location.query_str());
+        if navigator.push_with_query(&AppRoute::Home, &[("redirect", return_to)]).is_err() 
+        {
+            navigator.push(&AppRoute::Home);
+        }
         html! {}
     }
 }
\ No newline at end of file
@@ -0,0 +1,49 @@
+use crate::{contexts::{config_context::use_config, user_context::{use_user, UserAction}}, router::AppRoute, services::auth_service};
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+/// Shown over the current page once the API reports the session as
+/// expired. Logging in again brings the user back to the page they were on.
+#[function_component(SessionExpiredModal)]
+pub fn session_expired_modal() -> Html 
+{
+    let (i18n, _) = use_translation();
+    let user_context = use_user();
+    let navigator = use_navigator().unwrap();
+    let config = use_config();
+
+    let Some(return_to) = &user_context.session_expired 
+    else 
+    {
+        return html! {};
+    };
+
+    let login_url = auth_service::login_url(&config.cas_login_url, Some(return_to));
+
+    let on_dismiss = 
+    {
+        let user_context = user_context.clone();
+        Callback::from(move |_| 
+        {
+            user_context.dispatch(UserAction::DismissSessionExpired);
+            navigator.push(&AppRoute::Home);
+        })
+    };
+
+    html! 
+    {
+        <div class="modal-backdrop">
+            <div class="card modal" role="dialog" aria-modal="true">
+                <h2>{ i18n.t("auth.session_expired_title") }</h2>
+                <p>{ i18n.t("auth.session_expired_message") }</p>
+                <div class="modal-actions">
+                    <button class="button-danger" onclick={on_dismiss}>
+                        { i18n.t("auth.session_expired_dismiss") }
+                    </button>
+                    <a href={login_url} class="button-gradient">{ i18n.t("auth.session_expired_login") }</a>
+                </div>
+            </div>
+        </div>
+    }
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::models::container_status::ContainerStatus;
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct StatusBadgeProps 
+{
+    /// `None` while the status is still loading.
+    pub status: Option<ContainerStatus>,
+}
+
+#[function_component(StatusBadge)]
+pub fn status_badge(props: &StatusBadgeProps) -> Html 
+{
+    let (i18n, _) = use_translation();
+
+    let (badge_class, text) = match props.status 
+    {
+        Some(status) => (status.badge_class(), i18n.t(status.translation_key())),
+        None => (ContainerStatus::Unknown.badge_class(), i18n.t("common.loading")),
+    };
+
+    html! 
+    {
+        <span class={classes!("status-badge", badge_class)}>
+            { text }
+        </span>
+    }
+}
\ No newline at end of file
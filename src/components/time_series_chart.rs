@@ -0,0 +1,398 @@
+use i18nrs::yew::use_translation;
+use wasm_bindgen::{JsCast, JsValue};
+use yew::prelude::*;
+
+const WIDTH: f64 = 400.0;
+const HEIGHT: f64 = 180.0;
+const MARGIN_LEFT: f64 = 64.0;
+const MARGIN_RIGHT: f64 = 8.0;
+const MARGIN_TOP: f64 = 8.0;
+const MARGIN_BOTTOM: f64 = 24.0;
+const Y_TICKS: usize = 4;
+
+const SPARKLINE_WIDTH: f64 = 100.0;
+const SPARKLINE_HEIGHT: f64 = 24.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChartUnit
+{
+    Percent,
+    Bytes,
+    BytesPerSecond,
+    Count,
+}
+
+impl ChartUnit
+{
+    pub fn format(self, value: f64) -> String
+    {
+        match self
+        {
+            ChartUnit::Percent => format!("{:.1} %", value),
+            ChartUnit::Bytes => format_bytes(value),
+            ChartUnit::BytesPerSecond => format!("{}/s", format_bytes(value)),
+            ChartUnit::Count => format!("{:.0}", value),
+        }
+    }
+}
+
+fn format_bytes(bytes: f64) -> String
+{
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1
+    {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0
+    {
+        format!("{:.0} {}", value, UNITS[unit])
+    }
+    else
+    {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChartPoint
+{
+    /// Unix timestamp in seconds.
+    pub timestamp: i64,
+    pub value: f64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartSeries
+{
+    pub label: String,
+    /// Any CSS color, used for the line, its area and the legend swatch.
+    pub color: String,
+    pub points: Vec<ChartPoint>,
+}
+
+/// Maps timestamps and values onto a drawing area. The y axis starts at zero
+/// and ends at `max_value`, or at the largest value when that is higher.
+#[derive(Clone, Copy)]
+struct Scale
+{
+    start: i64,
+    span: f64,
+    max_value: f64,
+    left: f64,
+    width: f64,
+    bottom: f64,
+    height: f64,
+}
+
+impl Scale
+{
+    fn new<'a>(points: impl Iterator<Item = &'a ChartPoint> + Clone, max_value: Option<f64>, left: f64, top: f64, width: f64, height: f64) -> Self
+    {
+        let start = points.clone().map(|p| p.timestamp).min().unwrap_or_default();
+        let end = points.clone().map(|p| p.timestamp).max().unwrap_or_default();
+        let max_value = points
+            .map(|p| p.value)
+            .fold(max_value.unwrap_or_default(), f64::max)
+            .max(f64::EPSILON);
+
+        Scale
+        {
+            start,
+            span: (end - start) as f64,
+            max_value,
+            left,
+            width,
+            bottom: top + height,
+            height,
+        }
+    }
+
+    fn x(&self, timestamp: i64) -> f64
+    {
+        if self.span <= 0.0
+        {
+            self.left + self.width
+        }
+        else
+        {
+            self.left + (timestamp - self.start) as f64 / self.span * self.width
+        }
+    }
+
+    fn y(&self, value: f64) -> f64
+    {
+        self.bottom - (value / self.max_value).clamp(0.0, 1.0) * self.height
+    }
+
+    fn timestamp_at(&self, x: f64) -> i64
+    {
+        let ratio = ((x - self.left) / self.width).clamp(0.0, 1.0);
+        self.start + (ratio * self.span).round() as i64
+    }
+
+    fn polyline(&self, points: &[ChartPoint]) -> String
+    {
+        points
+            .iter()
+            .map(|p| format!("{:.1},{:.1}", self.x(p.timestamp), self.y(p.value)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn area(&self, points: &[ChartPoint]) -> String
+    {
+        match (points.first(), points.last())
+        {
+            (Some(first), Some(last)) => format!(
+                "{:.1},{:.1} {} {:.1},{:.1}",
+                self.x(first.timestamp),
+                self.bottom,
+                self.polyline(points),
+                self.x(last.timestamp),
+                self.bottom
+            ),
+            _ => String::new(),
+        }
+    }
+}
+
+fn nearest_point(points: &[ChartPoint], timestamp: i64) -> Option<&ChartPoint>
+{
+    points.iter().min_by_key(|p| (p.timestamp - timestamp).abs())
+}
+
+fn format_time(timestamp: i64, language: &str, show_date: bool) -> String
+{
+    let date = js_sys::Date::new(&JsValue::from_f64(timestamp as f64 * 1000.0));
+    if show_date
+    {
+        date.to_locale_string(language, &JsValue::UNDEFINED).into()
+    }
+    else
+    {
+        date.to_locale_time_string(language).into()
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct TimeSeriesChartProps
+{
+    pub series: Vec<ChartSeries>,
+    pub unit: ChartUnit,
+    #[prop_or_default]
+    pub title: Option<String>,
+    /// Upper bound of the y axis; the data wins when it goes higher.
+    #[prop_or_default]
+    pub max_value: Option<f64>,
+    /// Label the time axis with dates as well, for multi-day ranges.
+    #[prop_or_default]
+    pub show_dates: bool,
+}
+
+#[function_component(TimeSeriesChart)]
+pub fn time_series_chart(props: &TimeSeriesChartProps) -> Html
+{
+    let (i18n, _) = use_translation();
+    let hovered_timestamp = use_state(|| None::<i64>);
+
+    let all_points = props.series.iter().flat_map(|s| s.points.iter());
+    let scale = Scale::new(
+        all_points,
+        props.max_value,
+        MARGIN_LEFT,
+        MARGIN_TOP,
+        WIDTH - MARGIN_LEFT - MARGIN_RIGHT,
+        HEIGHT - MARGIN_TOP - MARGIN_BOTTOM,
+    );
+    let language = i18n.get_current_language();
+    let has_points = props.series.iter().any(|s| !s.points.is_empty());
+
+    let on_mouse_move =
+    {
+        let hovered_timestamp = hovered_timestamp.clone();
+        Callback::from(move |e: MouseEvent|
+        {
+            let Some(svg) = e.current_target().and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            else
+            {
+                return;
+            };
+            let rect = svg.get_bounding_client_rect();
+            if rect.width() <= 0.0
+            {
+                return;
+            }
+            let x = (e.client_x() as f64 - rect.left()) / rect.width() * WIDTH;
+            hovered_timestamp.set(Some(scale.timestamp_at(x)));
+        })
+    };
+
+    let on_mouse_leave =
+    {
+        let hovered_timestamp = hovered_timestamp.clone();
+        Callback::from(move |_: MouseEvent| hovered_timestamp.set(None))
+    };
+
+    let y_ticks = (0..=Y_TICKS).map(|tick|
+    {
+        let value = scale.max_value * tick as f64 / Y_TICKS as f64;
+        let y = scale.y(value);
+        html!
+        {
+            <g class="chart-y-tick">
+                <line class="chart-grid-line" x1={MARGIN_LEFT.to_string()} x2={(WIDTH - MARGIN_RIGHT).to_string()} y1={y.to_string()} y2={y.to_string()} />
+                <text class="chart-axis-label" x={(MARGIN_LEFT - 6.0).to_string()} y={y.to_string()} text-anchor="end" dominant-baseline="middle">
+                    { props.unit.format(value) }
+                </text>
+            </g>
+        }
+    });
+
+    let x_labels = has_points.then(||
+    {
+        let end = scale.start + scale.span as i64;
+        [(scale.start, "start"), (scale.start + scale.span as i64 / 2, "middle"), (end, "end")]
+            .into_iter()
+            .map(|(timestamp, anchor)|
+            {
+                html!
+                {
+                    <text class="chart-axis-label" x={scale.x(timestamp).to_string()} y={(HEIGHT - 6.0).to_string()} text-anchor={anchor}>
+                        { format_time(timestamp, language, props.show_dates) }
+                    </text>
+                }
+            })
+            .collect::<Html>()
+    });
+
+    let hover = (*hovered_timestamp).filter(|_| has_points).map(|timestamp|
+    {
+        let rows: Vec<(&ChartSeries, &ChartPoint)> = props
+            .series
+            .iter()
+            .filter_map(|s| nearest_point(&s.points, timestamp).map(|p| (s, p)))
+            .collect();
+        let snapped = rows.first().map(|(_, p)| p.timestamp).unwrap_or(timestamp);
+        let x = scale.x(snapped);
+        let tooltip_side = if x > WIDTH / 2.0 { "chart-tooltip-left" } else { "chart-tooltip-right" };
+
+        let marker = html!
+        {
+            <g class="chart-hover">
+                <line class="chart-hover-line" x1={x.to_string()} x2={x.to_string()} y1={MARGIN_TOP.to_string()} y2={scale.bottom.to_string()} />
+                {
+                    for rows.iter().map(|(series, point)| html!
+                    {
+                        <circle cx={scale.x(point.timestamp).to_string()} cy={scale.y(point.value).to_string()} r="3" style={format!("fill: {};", series.color)} />
+                    })
+                }
+            </g>
+        };
+
+        let tooltip = html!
+        {
+            <div class={classes!("chart-tooltip", tooltip_side)} style={format!("left: {:.2}%;", x / WIDTH * 100.0)}>
+                <div class="chart-tooltip-time">{ format_time(snapped, language, true) }</div>
+                {
+                    for rows.iter().map(|(series, point)| html!
+                    {
+                        <div class="chart-tooltip-row">
+                            <span class="chart-legend-swatch" style={format!("background-color: {};", series.color)} />
+                            <span>{ &series.label }</span>
+                            <strong>{ props.unit.format(point.value) }</strong>
+                        </div>
+                    })
+                }
+            </div>
+        };
+
+        (marker, tooltip)
+    });
+    let (hover_marker, tooltip) = hover.unzip();
+
+    html!
+    {
+        <div class="chart">
+            if let Some(title) = &props.title
+            {
+                <div class="chart-header">
+                    <span class="chart-title">{ title }</span>
+                    if props.series.len() > 1
+                    {
+                        <div class="chart-legend">
+                            {
+                                for props.series.iter().map(|series| html!
+                                {
+                                    <span class="chart-legend-item">
+                                        <span class="chart-legend-swatch" style={format!("background-color: {};", series.color)} />
+                                        { &series.label }
+                                    </span>
+                                })
+                            }
+                        </div>
+                    }
+                </div>
+            }
+            <div class="chart-plot">
+                <svg
+                    class="chart-svg"
+                    viewBox={format!("0 0 {} {}", WIDTH, HEIGHT)}
+                    onmousemove={on_mouse_move}
+                    onmouseleave={on_mouse_leave}
+                >
+                    { for y_ticks }
+                    { x_labels.unwrap_or_default() }
+                    {
+                        for props.series.iter().map(|series| html!
+                        {
+                            <g class="chart-series">
+                                <polygon class="chart-area" points={scale.area(&series.points)} style={format!("fill: {};", series.color)} />
+                                <polyline class="chart-line" points={scale.polyline(&series.points)} style={format!("stroke: {};", series.color)} />
+                            </g>
+                        })
+                    }
+                    { hover_marker.unwrap_or_default() }
+                </svg>
+                { tooltip.unwrap_or_default() }
+            </div>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct SparklineProps
+{
+    pub points: Vec<ChartPoint>,
+    pub unit: ChartUnit,
+    #[prop_or_default]
+    pub max_value: Option<f64>,
+    #[prop_or(String::from("var(--color-primary-accent)"))]
+    pub color: String,
+}
+
+/// Axis-less variant of `TimeSeriesChart` sized to sit inline with text.
+/// Hovering shows the latest value through the native tooltip.
+#[function_component(Sparkline)]
+pub fn sparkline(props: &SparklineProps) -> Html
+{
+    let scale = Scale::new(props.points.iter(), props.max_value, 1.0, 1.0, SPARKLINE_WIDTH - 2.0, SPARKLINE_HEIGHT - 2.0);
+    let latest = props.points.last().map(|p| props.unit.format(p.value)).unwrap_or_default();
+
+    html!
+    {
+        <svg
+            class="sparkline"
+            viewBox={format!("0 0 {} {}", SPARKLINE_WIDTH, SPARKLINE_HEIGHT)}
+            preserveAspectRatio="none"
+        >
+            <title>{ latest }</title>
+            <polygon class="chart-area" points={scale.area(&props.points)} style={format!("fill: {};", props.color)} />
+            <polyline class="chart-line" points={scale.polyline(&props.points)} style={format!("stroke: {};", props.color)} />
+        </svg>
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{contexts::user_context::use_user, hooks::use_abort_scope::use_abort_scope, services::api_client::{self, ApiError}};
+use std::{any::Any, cell::RefCell, collections::HashMap, future::Future, rc::Rc};
+use yew::prelude::*;
+
+/// Number of times each key prefix was invalidated. Readers revalidate when
+/// the count covering their key changes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CacheVersions
+{
+    versions: HashMap<String, u32>,
+}
+
+impl Reducible for CacheVersions
+{
+    type Action = Vec<String>;
+
+    fn reduce(self: Rc<Self>, prefixes: Self::Action) -> Rc<Self>
+    {
+        let mut next = (*self).clone();
+        for prefix in prefixes
+        {
+            *next.versions.entry(prefix).or_default() += 1;
+        }
+        next.into()
+    }
+}
+
+/// Last response of each endpoint, keyed by its path (`/projects/owned`,
+/// `/projects/42`, ...).
+#[derive(Clone)]
+pub struct CacheContext
+{
+    entries: Rc<RefCell<HashMap<String, Rc<dyn Any>>>>,
+    versions: UseReducerHandle<CacheVersions>,
+}
+
+impl PartialEq for CacheContext
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        Rc::ptr_eq(&self.entries, &other.entries) && self.versions == other.versions
+    }
+}
+
+/// `/projects` covers `/projects` and everything under it; `""` covers
+/// every key.
+fn covers(prefix: &str, key: &str) -> bool
+{
+    key.strip_prefix(prefix).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Key prefixes made stale by a successful mutation of `path`.
+fn stale_prefixes(path: &str) -> Vec<String>
+{
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice()
+    {
+        // Deploying can create a database along with the project
+        ["projects", "deploy"] => vec!["/projects/owned".to_string(), "/databases".to_string()],
+        ["projects", id, rest @ ..] =>
+        {
+            let mut prefixes = vec![
+                format!("/projects/{}", id),
+                "/projects/owned".to_string(),
+                "/projects/participations".to_string(),
+            ];
+            // Purging a project unlinks its database
+            if rest.is_empty() || rest[0] == "database"
+            {
+                prefixes.push("/databases".to_string());
+            }
+            prefixes
+        }
+        // A database shows up in the details of the project it is linked to
+        ["databases", ..] => vec!["/databases".to_string(), "/projects".to_string()],
+        _ => vec![String::new()],
+    }
+}
+
+impl CacheContext
+{
+    fn get<T: Clone + 'static>(&self, key: &str) -> Option<T>
+    {
+        self.entries.borrow().get(key).and_then(|entry| entry.downcast_ref::<T>()).cloned()
+    }
+
+    fn insert<T: 'static>(&self, key: String, value: T)
+    {
+        self.entries.borrow_mut().insert(key, Rc::new(value));
+    }
+
+    fn version(&self, key: &str) -> u32
+    {
+        self.versions
+            .versions
+            .iter()
+            .filter(|(prefix, _)| covers(prefix, key))
+            .map(|(_, version)| version)
+            .sum()
+    }
+
+    /// Drops every entry under `prefixes`; mounted readers of those keys
+    /// keep their data and revalidate. The empty prefix covers every key.
+    pub fn invalidate(&self, prefixes: Vec<String>)
+    {
+        self.entries
+            .borrow_mut()
+            .retain(|key, _| !prefixes.iter().any(|prefix| covers(prefix, key)));
+        self.versions.dispatch(prefixes);
+    }
+}
+
+/// What `use_cached` last fetched, tagged with its key so a change of key
+/// never shows the previous data.
+struct Fetched<T>
+{
+    shown: Option<(String, Result<T, ApiError>)>,
+}
+
+impl<T> Reducible for Fetched<T>
+{
+    type Action = (String, Result<T, ApiError>);
+
+    fn reduce(self: Rc<Self>, (key, result): Self::Action) -> Rc<Self>
+    {
+        // A failed revalidation keeps the data already shown
+        if result.is_err() && matches!(&self.shown, Some((shown, Ok(_))) if *shown == key)
+        {
+            return self;
+        }
+        Fetched { shown: Some((key, result)) }.into()
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CacheProviderProps
+{
+    pub children: Children,
+}
+
+/// Holds the cache for the whole app. Every successful mutation sent
+/// through the API client invalidates the entries it affects, and the cache
+/// is emptied when the logged-in user changes.
+#[function_component(CacheProvider)]
+pub fn cache_provider(props: &CacheProviderProps) -> Html
+{
+    let user_context = use_user();
+    let entries = use_mut_ref(HashMap::new);
+    let versions = use_reducer(CacheVersions::default);
+    let cache = CacheContext { entries, versions };
+
+    {
+        let cache = cache.clone();
+        use_effect_with((), move |_|
+        {
+            api_client::on_mutation(move |path| cache.invalidate(stale_prefixes(path)));
+            || ()
+        });
+    }
+
+    {
+        let cache = cache.clone();
+        let login = user_context.user.as_ref().map(|user| user.login.clone());
+        use_effect_with(login, move |_|
+        {
+            // Nothing is mounted that read the previous user's data
+            cache.entries.borrow_mut().clear();
+            || ()
+        });
+    }
+
+    html!
+    {
+        <ContextProvider<CacheContext> context={cache}>
+            {props.children.clone()}
+        </ContextProvider<CacheContext>>
+    }
+}
+
+#[hook]
+pub fn use_cache() -> CacheContext
+{
+    use_context::<CacheContext>().expect("use_cache must be used within a CacheProvider")
+}
+
+/// Returns the cached response of `key` right away, if any, and revalidates
+/// it with `fetch` on mount and after every invalidation. A failed
+/// revalidation keeps the data already shown.
+#[hook]
+pub fn use_cached<T, F, Fut>(key: String, fetch: F) -> Option<Result<T, ApiError>>
+where
+    T: Clone + 'static,
+    F: FnOnce() -> Fut + 'static,
+    Fut: Future<Output = Result<T, ApiError>> + 'static,
+{
+    let cache = use_cache();
+    let scope = use_abort_scope();
+    let fetched = use_reducer(|| Fetched::<T> { shown: None });
+    let version = cache.version(&key);
+
+    {
+        let cache = cache.clone();
+        let fetched = fetched.dispatcher();
+        use_effect_with((key.clone(), version), move |(key, _)|
+        {
+            let key = key.clone();
+            scope.spawn(async move
+            {
+                match fetch().await
+                {
+                    Ok(value) =>
+                    {
+                        cache.insert(key.clone(), value.clone());
+                        fetched.dispatch((key, Ok(value)));
+                    }
+                    Err(e) =>
+                    {
+                        log::warn!("Could not load {}: {}", key, e);
+                        // On a fresh mount the cached data is what is shown
+                        if cache.get::<T>(&key).is_none()
+                        {
+                            fetched.dispatch((key, Err(e)));
+                        }
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
+    match &fetched.shown
+    {
+        Some((shown, result)) if *shown == key => Some(result.clone()),
+        _ => cache.get::<T>(&key).map(Ok),
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::models::error_code::ErrorCode;
+
+    fn is_stale(mutation: &str, key: &str) -> bool
+    {
+        stale_prefixes(mutation).iter().any(|prefix| covers(prefix, key))
+    }
+
+    #[test]
+    fn prefix_covers_whole_segments_only()
+    {
+        assert!(covers("/projects/4", "/projects/4"));
+        assert!(!covers("/projects/4", "/projects/42"));
+        assert!(covers("/projects", "/projects/owned"));
+        assert!(covers("", "/databases/mine"));
+    }
+
+    #[test]
+    fn mutations_invalidate_what_they_change()
+    {
+        assert!(is_stale("/projects/4/env", "/projects/4"));
+        assert!(!is_stale("/projects/4/env", "/projects/5"));
+        assert!(!is_stale("/projects/4/start", "/databases/mine"));
+        assert!(is_stale("/projects/4/database/7", "/databases/mine"));
+        assert!(is_stale("/projects/deploy", "/projects/owned"));
+        assert!(is_stale("/databases/7", "/projects/5"));
+    }
+
+    #[test]
+    fn failed_revalidation_keeps_shown_data()
+    {
+        let error = || ApiError::new(ErrorCode::NetworkError, None);
+        let shown = Rc::new(Fetched { shown: Some(("/projects/4".to_string(), Ok(1))) });
+
+        let after_error = shown.clone().reduce(("/projects/4".to_string(), Err(error())));
+        assert!(matches!(after_error.shown, Some((_, Ok(1)))));
+
+        let other_key = shown.reduce(("/projects/5".to_string(), Err(error())));
+        assert!(matches!(&other_key.shown, Some((key, Err(_))) if key == "/projects/5"));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{models::app_config::AppConfig, services::api_client::{self, HangarClient}};
+use gloo_net::http::Request;
+use i18nrs::yew::use_translation;
+use std::rc::Rc;
+use yew::prelude::*;
+
+const CONFIG_URL: &str = "/config.json";
+
+pub type ConfigContext = Rc<AppConfig>;
+
+#[derive(Properties, PartialEq)]
+pub struct ConfigProviderProps 
+{
+    pub children: Children,
+}
+
+/// Fails rather than falling back to built-in URLs, which would send a
+/// staging deploy with a broken file to production.
+async fn load_config() -> Result<AppConfig, String> 
+{
+    let response = Request::get(CONFIG_URL)
+        .send()
+        .await
+        .map_err(|e| format!("Could not fetch {}: {}", CONFIG_URL, e))?;
+    if !response.ok() 
+    {
+        return Err(format!("{} returned {}", CONFIG_URL, response.status()));
+    }
+
+    response
+        .json::<AppConfig>()
+        .await
+        .map_err(|e| format!("Invalid {}: {}", CONFIG_URL, e))
+}
+
+/// Loads the runtime configuration and points the API client at the
+/// configured base URL. Children are only rendered once that is done, so
+/// no request goes out against the wrong backend; if the file cannot be
+/// loaded, an error is shown instead of the app.
+#[function_component(ConfigProvider)]
+pub fn config_provider(props: &ConfigProviderProps) -> Html 
+{
+    let (i18n, _) = use_translation();
+    let config = use_state(|| None::<Result<ConfigContext, String>>);
+
+    {
+        let config = config.clone();
+        use_effect_with((), move |_| 
+        {
+            wasm_bindgen_futures::spawn_local(async move 
+            {
+                match load_config().await 
+                {
+                    Ok(loaded) => 
+                    {
+                        api_client::configure(
+                            HangarClient::new(&loaded.api_base_url).with_header("X-Requested-With", "XMLHttpRequest"),
+                        );
+                        config.set(Some(Ok(Rc::new(loaded))));
+                    }
+                    Err(e) => 
+                    {
+                        log::error!("{}", e);
+                        config.set(Some(Err(e)));
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
+    match &*config 
+    {
+        Some(Ok(config)) => html! 
+        {
+            <ContextProvider<ConfigContext> context={config.clone()}>
+                {props.children.clone()}
+            </ContextProvider<ConfigContext>>
+        },
+        Some(Err(e)) => html! 
+        {
+            <div class="card error" role="alert">
+                <h2>{ i18n.t("config.load_failed_title") }</h2>
+                <p>{ i18n.t("config.load_failed_message") }</p>
+                <pre>{ e }</pre>
+            </div>
+        },
+        None => html! {},
+    }
+}
+
+#[hook]
+pub fn use_config() -> ConfigContext 
+{
+    use_context::<ConfigContext>().expect("use_config must be used within a ConfigProvider")
+}
\ No newline at end of file
@@ -1 +1,3 @@
+pub mod cache_context;
+pub mod config_context;
 pub mod user_context;
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{models::user::User, services::auth_service};
+use crate::{models::user::User, services::{api_client, auth_service}};
 use std::rc::Rc;
 use yew::prelude::*;
 
@@ -7,6 +7,17 @@ pub struct UserState
 {
     pub user: Option<User>,
     pub loading: bool,
+    /// Page the user was on when a request came back unauthorized while
+    /// they were logged in. Logging in again brings them back to it.
+    pub session_expired: Option<String>,
+}
+
+pub enum UserAction 
+{
+    Set(Option<User>),
+    /// Carries the path (with its query) of the page being shown.
+    SessionExpired(String),
+    DismissSessionExpired,
 }
 
 pub type UserContext = UseReducerHandle<UserState>;
@@ -20,18 +31,43 @@ pub struct UserProviderProps
 
 impl Reducible for UserState 
 {
-    type Action = Option<User>;
+    type Action = UserAction;
 
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> 
     {
-        UserState 
+        match action 
         {
-            user: action,
-            loading: false,
-        }.into()
+            UserAction::Set(user) => UserState 
+            {
+                user,
+                loading: false,
+                session_expired: None,
+            }.into(),
+            // A 401 before anyone logged in (e.g. the initial /auth/me) is
+            // not an expired session
+            UserAction::SessionExpired(_) if self.user.is_none() => self,
+            UserAction::SessionExpired(return_to) => UserState 
+            {
+                user: None,
+                loading: false,
+                session_expired: Some(return_to),
+            }.into(),
+            UserAction::DismissSessionExpired => UserState 
+            {
+                session_expired: None,
+                ..(*self).clone()
+            }.into(),
+        }
     }
 }
 
+/// Path and query of the page being shown.
+fn current_path() -> String
+{
+    let location = web_sys::window().unwrap().location();
+    format!("{}{}", location.pathname().unwrap_or_default(), location.search().unwrap_or_default())
+}
+
 #[function_component(UserProvider)]
 pub fn user_provider(props: &UserProviderProps) -> Html 
 {
@@ -39,9 +75,19 @@ pub fn user_provider(props: &UserProviderProps) -> Html
     {
         user: None,
         loading: true,
+        session_expired: None,
     });
 
 
+    {
+        let dispatcher = user_state.dispatcher();
+        use_effect_with((), move |_| 
+        {
+            api_client::on_unauthorized(move || dispatcher.dispatch(UserAction::SessionExpired(current_path())));
+            || ()
+        });
+    }
+
     {
         let user_state = user_state.clone();
         use_effect_with((), move |_| 
@@ -49,7 +95,7 @@ pub fn user_provider(props: &UserProviderProps) -> Html
             wasm_bindgen_futures::spawn_local(async move 
             {
                 let current_user = auth_service::get_current_user().await.ok();
-                user_state.dispatch(current_user);
+                user_state.dispatch(UserAction::Set(current_user));
             });
             || ()
         });
@@ -67,4 +113,51 @@ pub fn user_provider(props: &UserProviderProps) -> Html
 pub fn use_user() -> UserContext 
 {
     use_context::<UserContext>().expect("use_user must be used within a UserProvider")
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{pages::auth_callback::return_route, router::AppRoute};
+
+    fn logged_in() -> Rc<UserState>
+    {
+        Rc::new(UserState
+        {
+            user: Some(User
+            {
+                login: "jdoe".to_string(),
+                name: "Jane Doe".to_string(),
+                email: "jdoe@example.com".to_string(),
+                is_admin: false,
+            }),
+            loading: false,
+            session_expired: None,
+        })
+    }
+
+    #[test]
+    fn unauthorized_before_login_is_not_an_expired_session()
+    {
+        let state = Rc::new(UserState { user: None, loading: true, session_expired: None });
+        let state = state.reduce(UserAction::SessionExpired("/".to_string()));
+        assert_eq!(state.session_expired, None);
+    }
+
+    #[test]
+    fn expired_session_brings_the_user_back_to_the_same_page()
+    {
+        let user = logged_in().user.clone();
+        let expired = logged_in().reduce(UserAction::SessionExpired("/projects/42?tab=logs".to_string()));
+        assert_eq!(expired.user, None);
+
+        let return_to = expired.session_expired.clone().expect("expired session keeps the page");
+        let (route, query) = return_route(&return_to).expect("saved page is an in-app path");
+        assert!(route == AppRoute::ProjectDashboard { id: 42 });
+        assert_eq!(query, "tab=logs");
+
+        let logged_back_in = expired.reduce(UserAction::Set(user));
+        assert_eq!(logged_back_in.session_expired, None);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod use_abort_scope;
+pub mod use_polling;
+pub mod use_project_statuses;
\ No newline at end of file
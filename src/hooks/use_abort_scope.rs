@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::channel::oneshot;
+use futures::future::{self, FutureExt, Shared};
+use web_sys::AbortController;
+use yew::prelude::*;
+
+use crate::services::api_client;
+
+struct ScopeState
+{
+    controller: Option<AbortController>,
+    /// Resolves once the scope is aborted, when `abort` drops the sender.
+    aborted: Shared<oneshot::Receiver<()>>,
+    abort: RefCell<Option<oneshot::Sender<()>>>,
+}
+
+/// Group of tasks cancelled together. Aborting the scope drops every task
+/// spawned in it and cancels their in-flight requests, so their results
+/// never reach state that is gone.
+#[derive(Clone)]
+pub struct AbortScope
+{
+    state: Rc<ScopeState>,
+}
+
+impl AbortScope
+{
+    pub fn new() -> Self
+    {
+        let (sender, receiver) = oneshot::channel();
+        AbortScope
+        {
+            state: Rc::new(ScopeState
+            {
+                controller: AbortController::new().ok(),
+                aborted: receiver.shared(),
+                abort: RefCell::new(Some(sender)),
+            }),
+        }
+    }
+
+    /// Like `wasm_bindgen_futures::spawn_local`, but stops with the scope.
+    pub fn spawn(&self, task: impl Future<Output = ()> + 'static)
+    {
+        let aborted = self.state.aborted.clone();
+        let signal = self.state.controller.as_ref().map(AbortController::signal);
+
+        wasm_bindgen_futures::spawn_local(async move
+        {
+            let task = async move
+            {
+                match signal
+                {
+                    Some(signal) => api_client::with_abort_signal(signal, task).await,
+                    None => task.await,
+                }
+            };
+            future::select(std::pin::pin!(task), aborted).await;
+        });
+    }
+
+    pub fn abort(&self)
+    {
+        self.state.abort.borrow_mut().take();
+        if let Some(controller) = &self.state.controller
+        {
+            controller.abort();
+        }
+    }
+}
+
+impl Default for AbortScope
+{
+    fn default() -> Self
+    {
+        AbortScope::new()
+    }
+}
+
+/// Scope aborted when the component unmounts. Spawn the component's
+/// requests in it instead of calling `spawn_local` directly.
+#[hook]
+pub fn use_abort_scope() -> AbortScope
+{
+    let scope = use_memo((), |_| AbortScope::new());
+
+    {
+        let scope = scope.clone();
+        use_effect_with((), move |_| move || scope.abort());
+    }
+
+    (*scope).clone()
+}
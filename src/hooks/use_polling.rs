@@ -0,0 +1,412 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::future::{LocalBoxFuture, Shared};
+use futures::{channel::oneshot, FutureExt};
+use gloo_events::EventListener;
+use gloo_timers::future::TimeoutFuture;
+use yew::prelude::*;
+
+use crate::hooks::use_abort_scope::AbortScope;
+use crate::models::error_code::ErrorCode;
+use crate::services::api_client::ApiError;
+
+/// Longest wait between two polls while requests keep failing.
+const MAX_BACKOFF_MS: u32 = 60_000;
+
+/// Latest outcome of a polled resource. A failed poll keeps the last data
+/// and sets `error` until the next success.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PollingState<T>
+{
+    pub data: Option<T>,
+    pub error: Option<ApiError>,
+    /// `Date.now()` of the last successful poll, new on every success even
+    /// when the data is unchanged.
+    pub updated_at: Option<f64>,
+}
+
+impl<T> Default for PollingState<T>
+{
+    fn default() -> Self
+    {
+        PollingState
+        {
+            data: None,
+            error: None,
+            updated_at: None,
+        }
+    }
+}
+
+pub enum PollingAction<T>
+{
+    Success(T),
+    Failure(ApiError),
+}
+
+impl<T: Clone> Reducible for PollingState<T>
+{
+    type Action = PollingAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self>
+    {
+        match action
+        {
+            PollingAction::Success(data) => PollingState
+            {
+                data: Some(data),
+                error: None,
+                updated_at: Some(js_sys::Date::now()),
+            },
+            PollingAction::Failure(error) => PollingState
+            {
+                error: Some(error),
+                ..(*self).clone()
+            },
+        }
+        .into()
+    }
+}
+
+async fn wait_until_visible()
+{
+    let Some(document) = web_sys::window().and_then(|w| w.document())
+    else
+    {
+        return;
+    };
+    if !document.hidden()
+    {
+        return;
+    }
+
+    let (sender, receiver) = oneshot::channel();
+    let mut sender = Some(sender);
+    let _listener = EventListener::new(&document.clone(), "visibilitychange", move |_|
+    {
+        if !document.hidden()
+            && let Some(sender) = sender.take()
+        {
+            let _ = sender.send(());
+        }
+    });
+    let _ = receiver.await;
+}
+
+type SharedRequest = Shared<LocalBoxFuture<'static, Result<Rc<dyn Any>, ApiError>>>;
+
+/// A request that was just started, and how to cancel it.
+type StartedRequest<T> = (LocalBoxFuture<'static, Result<T, ApiError>>, Box<dyn FnOnce()>);
+
+/// A request shared by every poller of its key. It runs in its own scope,
+/// not in that of the component that started it, and is cancelled only
+/// once no poller waits for it anymore.
+struct InFlightRequest
+{
+    request: SharedRequest,
+    subscribers: usize,
+    cancel: Box<dyn FnOnce()>,
+}
+
+thread_local!
+{
+    /// Requests currently running, by polling key.
+    static IN_FLIGHT: RefCell<HashMap<String, InFlightRequest>> = RefCell::new(HashMap::new());
+}
+
+/// One poller waiting for a shared request. Dropping it before the request
+/// completes (the poller's component unmounted) lets the request go, and
+/// cancels it if it was the last one waiting.
+struct Subscription
+{
+    key: String,
+    request: SharedRequest,
+    completed: bool,
+}
+
+impl Drop for Subscription
+{
+    fn drop(&mut self)
+    {
+        let cancel = IN_FLIGHT.with(|in_flight|
+        {
+            let mut in_flight = in_flight.borrow_mut();
+            let entry = in_flight.get_mut(&self.key).filter(|entry| entry.request.ptr_eq(&self.request))?;
+            entry.subscribers -= 1;
+            // A completed request is dropped so the next poll fetches again
+            if entry.subscribers > 0 && !self.completed
+            {
+                return None;
+            }
+            let entry = in_flight.remove(&self.key)?;
+            (!self.completed).then_some(entry.cancel)
+        });
+
+        if let Some(cancel) = cancel
+        {
+            cancel();
+        }
+    }
+}
+
+/// Joins the request running under `key`, or starts one with `start`,
+/// which returns the request and a way to cancel it.
+async fn join_request<T>(key: &str, start: impl FnOnce() -> StartedRequest<T>) -> Result<T, ApiError>
+where
+    T: Clone + 'static,
+{
+    let request = IN_FLIGHT.with(|in_flight|
+    {
+        let mut in_flight = in_flight.borrow_mut();
+        let entry = in_flight.entry(key.to_string()).or_insert_with(||
+        {
+            let (request, cancel) = start();
+            InFlightRequest
+            {
+                request: request.map(|result| result.map(|data| Rc::new(data) as Rc<dyn Any>)).boxed_local().shared(),
+                subscribers: 0,
+                cancel,
+            }
+        });
+        entry.subscribers += 1;
+        entry.request.clone()
+    });
+    let mut subscription = Subscription { key: key.to_string(), request: request.clone(), completed: false };
+
+    let result = request.await;
+    subscription.completed = true;
+    drop(subscription);
+
+    result.map(|data| data.downcast_ref::<T>().expect("polling key shared by two data types").clone())
+}
+
+/// Runs `fetch`, or joins the request already running under `key`.
+async fn shared_request<T, F, Fut>(key: &str, fetch: &F) -> Result<T, ApiError>
+where
+    T: Clone + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, ApiError>> + 'static,
+{
+    join_request(key, ||
+    {
+        // Spawned rather than awaited in place so the request does not pick
+        // up the abort signal of whichever poller started it
+        let scope = AbortScope::new();
+        let (sender, receiver) = oneshot::channel();
+        let request = fetch();
+        scope.spawn(async move
+        {
+            let _ = sender.send(request.await);
+        });
+
+        let request = receiver
+            .map(|result| result.unwrap_or_else(|_| Err(ApiError::new(ErrorCode::RequestAborted, None))))
+            .boxed_local();
+        (request, Box::new(move || scope.abort()))
+    })
+    .await
+}
+
+/// `interval_ms` doubled for each failure in a row, capped at
+/// `MAX_BACKOFF_MS` unless the interval itself is longer.
+pub fn backoff_delay(interval_ms: u32, failures: u32) -> u32
+{
+    interval_ms.saturating_mul(2_u32.saturating_pow(failures)).min(MAX_BACKOFF_MS.max(interval_ms))
+}
+
+/// Calls `fetch` every `interval_ms` while the page is visible, backing off
+/// exponentially while it fails, and hands each outcome to `on_result`.
+/// Runs until dropped.
+pub async fn poll<T, F, Fut>(key: String, interval_ms: u32, fetch: F, on_result: impl Fn(Result<T, ApiError>))
+where
+    T: Clone + 'static,
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, ApiError>> + 'static,
+{
+    let mut failures = 0;
+    loop
+    {
+        wait_until_visible().await;
+
+        let result = shared_request(&key, &fetch).await;
+        // A cancelled request is neither a failure nor worth reporting
+        let aborted = matches!(&result, Err(e) if e.error_code == ErrorCode::RequestAborted);
+        if !aborted
+        {
+            failures = if result.is_ok() { 0 } else { failures + 1 };
+            on_result(result);
+        }
+
+        // A poll that comes due while hidden waits for the page to be shown
+        TimeoutFuture::new(backoff_delay(interval_ms, failures)).await;
+    }
+}
+
+/// Polls `fetch` every `interval_ms` while the page is visible, backing off
+/// exponentially while it fails. `key` names the resource: components
+/// polling the same key share requests that overlap.
+#[hook]
+pub fn use_polling<T, F, Fut>(key: String, interval_ms: u32, fetch: F) -> UseReducerHandle<PollingState<T>>
+where
+    T: Clone + PartialEq + 'static,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<T, ApiError>> + 'static,
+{
+    let state = use_reducer(PollingState::<T>::default);
+
+    {
+        let state = state.dispatcher();
+
+        use_effect_with((key, interval_ms), move |(key, interval_ms)|
+        {
+            let on_result = move |result| state.dispatch(match result
+            {
+                Ok(data) => PollingAction::Success(data),
+                Err(error) => PollingAction::Failure(error),
+            });
+            let scope = AbortScope::new();
+            scope.spawn(poll(key.clone(), *interval_ms, fetch, on_result));
+
+            move || scope.abort()
+        });
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use futures::executor::LocalPool;
+    use futures::task::LocalSpawnExt;
+    use std::cell::Cell;
+
+    type Answer = oneshot::Sender<Result<u32, ApiError>>;
+
+    /// A request answered through the returned sender, and a flag set when
+    /// it is cancelled.
+    fn controlled_request() -> (Answer, StartedRequest<u32>, Rc<Cell<bool>>)
+    {
+        let (sender, receiver) = oneshot::channel();
+        let cancelled = Rc::new(Cell::new(false));
+        let cancel = {
+            let cancelled = cancelled.clone();
+            Box::new(move || cancelled.set(true)) as Box<dyn FnOnce()>
+        };
+        (sender, (receiver.map(|result| result.unwrap()).boxed_local(), cancel), cancelled)
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap()
+    {
+        assert_eq!(backoff_delay(5000, 0), 5000);
+        assert_eq!(backoff_delay(5000, 1), 10_000);
+        assert_eq!(backoff_delay(5000, 3), 40_000);
+        assert_eq!(backoff_delay(5000, 4), MAX_BACKOFF_MS);
+        assert_eq!(backoff_delay(5000, 40), MAX_BACKOFF_MS);
+    }
+
+    #[test]
+    fn backoff_never_polls_faster_than_the_interval()
+    {
+        assert_eq!(backoff_delay(120_000, 0), 120_000);
+        assert_eq!(backoff_delay(120_000, 5), 120_000);
+    }
+
+    #[test]
+    fn overlapping_polls_share_one_request()
+    {
+        let mut pool = LocalPool::new();
+        let (sender, request, cancelled) = controlled_request();
+        let starts = Rc::new(Cell::new(0));
+        let results = Rc::new(RefCell::new(Vec::new()));
+
+        let mut request = Some(request);
+        for _ in 0..2
+        {
+            let (starts, results) = (starts.clone(), results.clone());
+            let request = request.take();
+            pool.spawner().spawn_local(async move
+            {
+                let result = join_request("shared", move ||
+                {
+                    starts.set(starts.get() + 1);
+                    request.unwrap()
+                })
+                .await;
+                results.borrow_mut().push(result.unwrap());
+            })
+            .unwrap();
+        }
+
+        pool.run_until_stalled();
+        sender.send(Ok(7)).unwrap();
+        pool.run_until_stalled();
+
+        assert_eq!(starts.get(), 1);
+        assert_eq!(*results.borrow(), vec![7, 7]);
+        assert!(!cancelled.get());
+        assert!(IN_FLIGHT.with(|in_flight| in_flight.borrow().is_empty()));
+    }
+
+    #[test]
+    fn request_outlives_the_poller_that_started_it()
+    {
+        let mut pool = LocalPool::new();
+        let (sender, request, cancelled) = controlled_request();
+        let result = Rc::new(Cell::new(None));
+
+        let first = pool.spawner()
+            .spawn_local_with_handle(async move
+            {
+                let _ = join_request("outlives", move || request).await;
+            })
+            .unwrap();
+        {
+            let result = result.clone();
+            pool.spawner()
+                .spawn_local(async move
+                {
+                    let joined = join_request::<u32>("outlives", || unreachable!("the request is already running")).await;
+                    result.set(Some(joined.unwrap()));
+                })
+                .unwrap();
+        }
+        pool.run_until_stalled();
+
+        // The component that started the request unmounts
+        drop(first);
+        pool.run_until_stalled();
+        assert!(!cancelled.get());
+
+        sender.send(Ok(3)).unwrap();
+        pool.run_until_stalled();
+        assert_eq!(result.get(), Some(3));
+    }
+
+    #[test]
+    fn request_is_cancelled_when_the_last_poller_leaves()
+    {
+        let mut pool = LocalPool::new();
+        let (_sender, request, cancelled) = controlled_request();
+
+        let poller = pool.spawner()
+            .spawn_local_with_handle(async move
+            {
+                let _ = join_request("cancelled", move || request).await;
+            })
+            .unwrap();
+        pool.run_until_stalled();
+        assert!(!cancelled.get());
+
+        drop(poller);
+        pool.run_until_stalled();
+        assert!(cancelled.get());
+        assert!(IN_FLIGHT.with(|in_flight| !in_flight.borrow().contains_key("cancelled")));
+    }
+}
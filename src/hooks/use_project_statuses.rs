@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::pin::pin;
+use std::rc::Rc;
+
+use futures::future::{self, Either};
+use futures::stream::LocalBoxStream;
+use futures::StreamExt;
+use gloo_timers::future::TimeoutFuture;
+use yew::prelude::*;
+
+use crate::hooks::use_abort_scope::AbortScope;
+use crate::hooks::use_polling::{backoff_delay, poll};
+use crate::models::container_status::{ContainerStatus, StatusEvent};
+use crate::services::api_client::ApiError;
+use crate::services::project_service;
+
+/// Poll interval used while the status stream is unavailable.
+const FALLBACK_POLL_INTERVAL_MS: u32 = 5000;
+/// First delay before reopening a dropped status stream, doubled after
+/// every failed attempt.
+const STREAM_RETRY_DELAY_MS: u32 = 2000;
+
+/// Last known status of each watched project.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProjectStatuses
+{
+    statuses: HashMap<i32, ContainerStatus>,
+}
+
+impl ProjectStatuses
+{
+    /// `None` until the first status of the project arrives.
+    pub fn get(&self, project_id: i32) -> Option<ContainerStatus>
+    {
+        self.statuses.get(&project_id).copied()
+    }
+}
+
+impl Reducible for ProjectStatuses
+{
+    type Action = Vec<StatusEvent>;
+
+    fn reduce(self: Rc<Self>, events: Self::Action) -> Rc<Self>
+    {
+        let mut next = (*self).clone();
+        next.statuses.extend(events.into_iter().map(|event| (event.project_id, event.status)));
+        if next == *self { self } else { next.into() }
+    }
+}
+
+pub struct LiveStatuses
+{
+    pub statuses: UseReducerHandle<ProjectStatuses>,
+    /// Reconnects and fetches every status again.
+    pub refresh: Callback<()>,
+}
+
+/// Fetches the status of each project, keeping those that answered. Fails
+/// only when none did.
+async fn fetch_statuses(project_ids: Vec<i32>) -> Result<Vec<StatusEvent>, ApiError>
+{
+    let results = future::join_all(project_ids.into_iter().map(|project_id| async move
+    {
+        project_service::get_project_status(project_id)
+            .await
+            .map(|status| StatusEvent { project_id, status })
+    }))
+    .await;
+
+    let mut events = Vec::new();
+    let mut last_error = None;
+    for result in results
+    {
+        match result
+        {
+            Ok(event) => events.push(event),
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    match last_error
+    {
+        Some(e) if events.is_empty() => Err(e),
+        _ => Ok(events),
+    }
+}
+
+type StatusStream = LocalBoxStream<'static, StatusEvent>;
+
+/// Opens the status stream and waits for its first message, which the
+/// server sends on connect. `None` if the stream could not be opened or
+/// closed before that.
+async fn connect(project_ids: &[i32]) -> Option<(StatusEvent, StatusStream)>
+{
+    match project_service::stream_project_statuses(project_ids)
+    {
+        Ok(mut events) => events.next().await.map(|first| (first, events)),
+        Err(e) =>
+        {
+            log::warn!("Status stream unavailable: {}", e);
+            None
+        }
+    }
+}
+
+/// Retries the status stream, backing off exponentially, until it is
+/// back.
+async fn reconnect(project_ids: &[i32]) -> (StatusEvent, StatusStream)
+{
+    let mut attempts = 0;
+    loop
+    {
+        TimeoutFuture::new(backoff_delay(STREAM_RETRY_DELAY_MS, attempts)).await;
+        if let Some(connected) = connect(project_ids).await
+        {
+            return connected;
+        }
+        attempts += 1;
+    }
+}
+
+async fn poll_statuses(project_ids: Vec<i32>, statuses: UseReducerDispatcher<ProjectStatuses>)
+{
+    let key = format!("projects/status?ids={:?}", project_ids);
+    poll(key, FALLBACK_POLL_INTERVAL_MS, move || fetch_statuses(project_ids.clone()), move |result|
+    {
+        if let Ok(events) = result
+        {
+            statuses.dispatch(events);
+        }
+    })
+    .await;
+}
+
+/// With `poll_while_down`, statuses are polled while the stream reconnects;
+/// otherwise they keep their last value until it is back.
+async fn follow_statuses(project_ids: Vec<i32>, statuses: UseReducerDispatcher<ProjectStatuses>, poll_while_down: bool)
+{
+    let mut connected = connect(&project_ids).await;
+    loop
+    {
+        if let Some((first, mut events)) = connected.take()
+        {
+            statuses.dispatch(vec![first]);
+            while let Some(event) = events.next().await
+            {
+                statuses.dispatch(vec![event]);
+            }
+            log::warn!("Status stream closed, polling until it reconnects");
+        }
+
+        if !poll_while_down
+        {
+            connected = Some(reconnect(&project_ids).await);
+            continue;
+        }
+
+        // Polling stops as soon as the stream is back
+        let polling = poll_statuses(project_ids.clone(), statuses.clone());
+        if let Either::Right((stream, _)) = future::select(pin!(polling), pin!(reconnect(&project_ids))).await
+        {
+            connected = Some(stream);
+        }
+    }
+}
+
+/// Follows the status of `project_ids` through the server's status stream,
+/// polling them instead while the stream is down and reconnecting. Polling
+/// sends one request per project, so keep this to short lists.
+#[hook]
+pub fn use_project_statuses(project_ids: Vec<i32>) -> LiveStatuses
+{
+    use_live_statuses(project_ids, true)
+}
+
+/// Like `use_project_statuses`, but never polls: for lists of any length,
+/// such as every project on the admin page. While the stream reconnects,
+/// statuses keep their last value.
+#[hook]
+pub fn use_streamed_project_statuses(project_ids: Vec<i32>) -> LiveStatuses
+{
+    use_live_statuses(project_ids, false)
+}
+
+#[hook]
+fn use_live_statuses(project_ids: Vec<i32>, poll_while_down: bool) -> LiveStatuses
+{
+    let statuses = use_reducer(ProjectStatuses::default);
+    let restart = use_state(|| 0_u32);
+
+    {
+        let statuses = statuses.dispatcher();
+
+        use_effect_with((project_ids, *restart), move |(project_ids, _)|
+        {
+            let scope = AbortScope::new();
+            if !project_ids.is_empty()
+            {
+                scope.spawn(follow_statuses(project_ids.clone(), statuses, poll_while_down));
+            }
+
+            move || scope.abort()
+        });
+    }
+
+    let refresh = Callback::from(move |_| restart.set(*restart + 1));
+
+    LiveStatuses { statuses, refresh }
+}
@@ -1,10 +1,13 @@
 mod app;
 mod components;
 mod contexts;
+mod hooks;
 mod models;
 mod pages;
 mod router;
 mod services;
+mod translations;
+mod utils;
 
 use app::App;
 
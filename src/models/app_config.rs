@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Deployment settings read from `/config.json` at startup. The endpoints
+/// have no default, so a file without them is rejected rather than sending
+/// a staging deploy to production; the other fields are safe to leave out.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct AppConfig
+{
+    pub api_base_url: String,
+    pub cas_login_url: String,
+    #[serde(default = "default_github_app_slug")]
+    pub github_app_slug: String,
+    #[serde(default = "default_support_email")]
+    pub support_email: String,
+    #[serde(default)]
+    pub features: HashMap<String, bool>,
+}
+
+fn default_github_app_slug() -> String
+{
+    "hangar-app".to_string()
+}
+
+fn default_support_email() -> String
+{
+    "dsi@garageisep.com".to_string()
+}
+
+impl AppConfig
+{
+    /// Flags are kill switches: anything not set to `false` is on.
+    pub fn is_enabled(&self, feature: &str) -> bool
+    {
+        self.features.get(feature).copied().unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn endpoints_are_required()
+    {
+        assert!(serde_json::from_str::<AppConfig>("{}").is_err());
+        // A misspelled key leaves the CAS URL missing
+        assert!(serde_json::from_str::<AppConfig>(r#"{"api_base_url": "/api", "cas_url": "https://cas.example/login"}"#).is_err());
+    }
+
+    #[test]
+    fn optional_fields_keep_their_defaults()
+    {
+        let config: AppConfig = serde_json::from_str(r#"{"api_base_url": "/api", "cas_login_url": "https://cas.example/login"}"#).unwrap();
+
+        assert_eq!(config.cas_login_url, "https://cas.example/login");
+        assert_eq!(config.github_app_slug, "hangar-app");
+        assert!(config.is_enabled("metrics_history"));
+    }
+}
@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+/// Docker container state as reported by `/projects/:id/status`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContainerStatus
+{
+    Running,
+    #[serde(alias = "stopped")]
+    Exited,
+    Restarting,
+    Paused,
+    Dead,
+    Created,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// One message of `/projects/status/stream`: sent for every watched project
+/// on connect, then whenever one changes state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub struct StatusEvent
+{
+    pub project_id: i32,
+    #[serde(default)]
+    pub status: ContainerStatus,
+}
+
+impl ContainerStatus
+{
+    pub fn badge_class(self) -> &'static str
+    {
+        match self
+        {
+            ContainerStatus::Running => "status-running",
+            ContainerStatus::Exited => "status-exited",
+            ContainerStatus::Restarting => "status-restarting",
+            ContainerStatus::Paused => "status-paused",
+            ContainerStatus::Dead => "status-dead",
+            ContainerStatus::Created => "status-created",
+            ContainerStatus::Unknown => "status-unknown",
+        }
+    }
+
+    pub fn translation_key(self) -> &'static str
+    {
+        match self
+        {
+            ContainerStatus::Running => "common.status_running",
+            ContainerStatus::Exited => "common.status_exited",
+            ContainerStatus::Restarting => "common.status_restarting",
+            ContainerStatus::Paused => "common.status_paused",
+            ContainerStatus::Dead => "common.status_dead",
+            ContainerStatus::Created => "common.status_created",
+            ContainerStatus::Unknown => "common.status_unknown",
+        }
+    }
+
+    /// Whether start/stop/restart make sense at all. A restarting
+    /// container is already changing state, and an unknown one may not
+    /// exist.
+    pub fn is_actionable(self) -> bool
+    {
+        !matches!(self, ContainerStatus::Restarting | ContainerStatus::Unknown)
+    }
+
+    pub fn can_start(self) -> bool
+    {
+        matches!(self, ContainerStatus::Exited | ContainerStatus::Created | ContainerStatus::Dead)
+    }
+
+    pub fn can_stop(self) -> bool
+    {
+        matches!(self, ContainerStatus::Running | ContainerStatus::Paused)
+    }
+
+    pub fn can_restart(self) -> bool
+    {
+        self.is_actionable()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    const ALL: [ContainerStatus; 7] = [
+        ContainerStatus::Running,
+        ContainerStatus::Exited,
+        ContainerStatus::Restarting,
+        ContainerStatus::Paused,
+        ContainerStatus::Dead,
+        ContainerStatus::Created,
+        ContainerStatus::Unknown,
+    ];
+
+    fn parse(status: &str) -> ContainerStatus
+    {
+        serde_json::from_value(serde_json::Value::from(status)).unwrap()
+    }
+
+    #[test]
+    fn docker_states_map_to_variants()
+    {
+        assert_eq!(parse("running"), ContainerStatus::Running);
+        assert_eq!(parse("exited"), ContainerStatus::Exited);
+        assert_eq!(parse("restarting"), ContainerStatus::Restarting);
+        assert_eq!(parse("created"), ContainerStatus::Created);
+    }
+
+    #[test]
+    fn stopped_is_an_alias_of_exited()
+    {
+        assert_eq!(parse("stopped"), ContainerStatus::Exited);
+        assert_eq!(serde_json::to_string(&ContainerStatus::Exited).unwrap(), r#""exited""#);
+    }
+
+    #[test]
+    fn unknown_strings_fall_back_to_unknown()
+    {
+        assert_eq!(parse("removing"), ContainerStatus::Unknown);
+        assert_eq!(parse("Running"), ContainerStatus::Unknown);
+        assert_eq!(parse(""), ContainerStatus::Unknown);
+    }
+
+    #[test]
+    fn badge_classes_are_distinct()
+    {
+        let classes: std::collections::HashSet<_> = ALL.iter().map(|status| status.badge_class()).collect();
+        assert_eq!(classes.len(), ALL.len());
+        assert_eq!(ContainerStatus::Running.badge_class(), "status-running");
+        assert_eq!(ContainerStatus::Unknown.badge_class(), "status-unknown");
+    }
+
+    #[test]
+    fn available_actions()
+    {
+        // (status, actionable, start, stop, restart)
+        let expected = [
+            (ContainerStatus::Running, true, false, true, true),
+            (ContainerStatus::Exited, true, true, false, true),
+            (ContainerStatus::Restarting, false, false, false, false),
+            (ContainerStatus::Paused, true, false, true, true),
+            (ContainerStatus::Dead, true, true, false, true),
+            (ContainerStatus::Created, true, true, false, true),
+            (ContainerStatus::Unknown, false, false, false, false),
+        ];
+
+        for (status, actionable, start, stop, restart) in expected
+        {
+            assert_eq!(status.is_actionable(), actionable, "{:?}", status);
+            assert_eq!(status.can_start(), start, "{:?}", status);
+            assert_eq!(status.can_stop(), stop, "{:?}", status);
+            assert_eq!(status.can_restart(), restart, "{:?}", status);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Error codes sent by the backend in `ApiError` bodies, plus the ones the
+/// client produces itself. Codes this build does not know about are kept
+/// verbatim in `Unknown`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ErrorCode
+{
+    NetworkError,
+    /// The request was cancelled through its abort signal, e.g. because the
+    /// component that sent it was unmounted.
+    RequestAborted,
+    /// No answer within the client's timeout.
+    RequestTimeout,
+    /// HTTP 429, whatever the body says.
+    RateLimited,
+    ResponseParseError,
+    ClientSerializationError,
+    ClientError,
+    Unauthorized,
+    NotFound,
+    ProjectNameTaken,
+    OwnerAlreadyExists,
+    InvalidProjectName,
+    InvalidImageUrl,
+    ImageScanFailed,
+    DeleteFailed,
+    OwnerCannotBeParticipant,
+    GithubAccountNotLinked,
+    GithubRepoNotAccessible,
+    GithubPackageNotPublic,
+    DatabaseAlreadyExists,
+    LinkFailed,
+    /// `HTTP_ERROR_<status>`, used when an error response has no body.
+    Http(u16),
+    Unknown(String),
+}
+
+/// Declares the wire name of each unit variant. The generated match has no
+/// catch-all arm, so a variant missing here does not compile.
+macro_rules! named_codes
+{
+    ($($variant:ident => $name:literal,)*) =>
+    {
+        impl ErrorCode
+        {
+            /// Wire name of a unit variant. `Http` and `Unknown` have none;
+            /// their code is written out by `Display`.
+            pub fn name(&self) -> Option<&'static str>
+            {
+                match self
+                {
+                    $(ErrorCode::$variant => Some($name),)*
+                    ErrorCode::Http(_) | ErrorCode::Unknown(_) => None,
+                }
+            }
+        }
+
+        /// Every unit variant, tried in turn when parsing a code.
+        const NAMED_CODES: &[ErrorCode] = &[$(ErrorCode::$variant),*];
+    };
+}
+
+named_codes!
+{
+    NetworkError => "NETWORK_ERROR",
+    RequestAborted => "REQUEST_ABORTED",
+    RequestTimeout => "REQUEST_TIMEOUT",
+    RateLimited => "RATE_LIMITED",
+    ResponseParseError => "RESPONSE_PARSE_ERROR",
+    ClientSerializationError => "CLIENT_SERIALIZATION_ERROR",
+    ClientError => "CLIENT_ERROR",
+    Unauthorized => "UNAUTHORIZED",
+    NotFound => "NOT_FOUND",
+    ProjectNameTaken => "PROJECT_NAME_TAKEN",
+    OwnerAlreadyExists => "OWNER_ALREADY_EXISTS",
+    InvalidProjectName => "INVALID_PROJECT_NAME",
+    InvalidImageUrl => "INVALID_IMAGE_URL",
+    ImageScanFailed => "IMAGE_SCAN_FAILED",
+    DeleteFailed => "DELETE_FAILED",
+    OwnerCannotBeParticipant => "OWNER_CANNOT_BE_PARTICIPANT",
+    GithubAccountNotLinked => "GITHUB_ACCOUNT_NOT_LINKED",
+    GithubRepoNotAccessible => "GITHUB_REPO_NOT_ACCESSIBLE",
+    GithubPackageNotPublic => "GITHUB_PACKAGE_NOT_PUBLIC",
+    DatabaseAlreadyExists => "DATABASE_ALREADY_EXISTS",
+    LinkFailed => "LINK_FAILED",
+}
+
+/// HTTP statuses with a dedicated message; others use `errors.HTTP_ERROR`.
+const TRANSLATED_HTTP_STATUSES: [u16; 6] = [400, 403, 404, 500, 502, 503];
+
+impl ErrorCode
+{
+    pub fn translation_key(&self) -> String
+    {
+        match self
+        {
+            ErrorCode::Http(status) if TRANSLATED_HTTP_STATUSES.contains(status) => format!("errors.HTTP_ERROR_{}", status),
+            ErrorCode::Http(_) => "errors.HTTP_ERROR".to_string(),
+            ErrorCode::Unknown(_) => "errors.DEFAULT".to_string(),
+            named => format!("errors.{}", named),
+        }
+    }
+}
+
+impl From<&str> for ErrorCode
+{
+    fn from(code: &str) -> Self
+    {
+        if let Some(named) = NAMED_CODES.iter().find(|named| named.name() == Some(code))
+        {
+            return named.clone();
+        }
+
+        match code.strip_prefix("HTTP_ERROR_").and_then(|status| status.parse().ok())
+        {
+            Some(status) => ErrorCode::Http(status),
+            None => ErrorCode::Unknown(code.to_string()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode
+{
+    fn from(code: String) -> Self
+    {
+        ErrorCode::from(code.as_str())
+    }
+}
+
+impl From<ErrorCode> for String
+{
+    fn from(code: ErrorCode) -> Self
+    {
+        code.to_string()
+    }
+}
+
+impl fmt::Display for ErrorCode
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            ErrorCode::Http(status) => write!(f, "HTTP_ERROR_{}", status),
+            ErrorCode::Unknown(code) => f.write_str(code),
+            named => f.write_str(named.name().unwrap_or_default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::translations::translations;
+    use serde_json::Value;
+
+    fn all_variants() -> Vec<ErrorCode>
+    {
+        NAMED_CODES
+            .iter()
+            .cloned()
+            .chain(TRANSLATED_HTTP_STATUSES.iter().map(|status| ErrorCode::Http(*status)))
+            .chain([ErrorCode::Http(418), ErrorCode::Unknown("SOMETHING_NEW".to_string())])
+            .collect()
+    }
+
+    fn lookup<'a>(json: &'a Value, key: &str) -> Option<&'a Value>
+    {
+        key.split('.').try_fold(json, |value, part| value.get(part))
+    }
+
+    #[test]
+    fn every_variant_is_translated_in_every_locale()
+    {
+        for (language, raw_json) in translations()
+        {
+            let json: Value = serde_json::from_str(raw_json)
+                .unwrap_or_else(|e| panic!("invalid {} translations: {}", language, e));
+
+            for code in all_variants()
+            {
+                let key = code.translation_key();
+                assert!(
+                    lookup(&json, &key).is_some_and(Value::is_string),
+                    "missing {} translation for {:?} ({})",
+                    language,
+                    code,
+                    key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn codes_round_trip_through_serde()
+    {
+        for code in all_variants()
+        {
+            let json = serde_json::to_string(&code).unwrap();
+            assert_eq!(serde_json::from_str::<ErrorCode>(&json).unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn parses_backend_strings()
+    {
+        assert_eq!(ErrorCode::from("GITHUB_ACCOUNT_NOT_LINKED"), ErrorCode::GithubAccountNotLinked);
+        assert_eq!(ErrorCode::from("HTTP_ERROR_502"), ErrorCode::Http(502));
+        assert_eq!(ErrorCode::from("HTTP_ERROR_abc"), ErrorCode::Unknown("HTTP_ERROR_abc".to_string()));
+        assert_eq!(ErrorCode::Http(418).translation_key(), "errors.HTTP_ERROR");
+        assert_eq!(ErrorCode::Unknown("X".to_string()).translation_key(), "errors.DEFAULT");
+        assert_eq!(ErrorCode::NotFound.name(), Some("NOT_FOUND"));
+        assert_eq!(ErrorCode::Http(502).name(), None);
+        assert_eq!(ErrorCode::Http(502).to_string(), "HTTP_ERROR_502");
+        assert_eq!(ErrorCode::Unknown("X".to_string()).to_string(), "X");
+    }
+}
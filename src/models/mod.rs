@@ -1,3 +1,6 @@
 pub mod user;
 pub mod project;
-pub mod database;
\ No newline at end of file
+pub mod app_config;
+pub mod container_status;
+pub mod database;
+pub mod error_code;
\ No newline at end of file
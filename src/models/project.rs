@@ -76,12 +76,66 @@ pub struct UpdateEnvPayload
     pub env_vars: HashMap<String, String>,
 }
 
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LogQuery
+{
+    pub tail: Option<u32>,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize)]
 pub struct ProjectMetrics
 {
     pub cpu_usage: f64,
     pub memory_usage: f64,
     pub memory_limit: f64,
+    /// Bytes per second, averaged since the previous sample.
+    #[serde(default)]
+    pub network_rx_rate: f64,
+    #[serde(default)]
+    pub network_tx_rate: f64,
+    #[serde(default)]
+    pub block_read_rate: f64,
+    #[serde(default)]
+    pub block_write_rate: f64,
+    #[serde(default)]
+    pub pids: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct MetricsSample
+{
+    pub timestamp: i64,
+    #[serde(flatten)]
+    pub metrics: ProjectMetrics,
+}
+
+#[derive(Deserialize)]
+pub struct MetricsHistoryResponse
+{
+    pub samples: Vec<MetricsSample>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MetricsHistoryRange
+{
+    Hour,
+    Day,
+    Week,
+}
+
+impl MetricsHistoryRange
+{
+    pub fn as_query(self) -> &'static str
+    {
+        match self
+        {
+            MetricsHistoryRange::Hour => "1h",
+            MetricsHistoryRange::Day => "24h",
+            MetricsHistoryRange::Week => "7d",
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize)]
@@ -7,4 +7,25 @@ pub struct User
     pub name: String,
     pub email: String,
     pub is_admin: bool,
+}
+
+/// Role required to see a route or a link.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Role 
+{
+    #[default]
+    User,
+    Admin,
+}
+
+impl User 
+{
+    pub fn has_role(&self, role: Role) -> bool 
+    {
+        match role 
+        {
+            Role::User => true,
+            Role::Admin => self.is_admin,
+        }
+    }
 }
\ No newline at end of file
@@ -1,3 +1,7 @@
+use crate::components::status_badge::StatusBadge;
+use crate::hooks::use_abort_scope::use_abort_scope;
+use crate::hooks::use_project_statuses::use_streamed_project_statuses;
+use crate::models::container_status::ContainerStatus;
 use crate::models::project::{DownProjectInfo, GlobalMetrics, Project};
 use crate::router::AppRoute;
 use crate::services::project_service;
@@ -29,6 +33,7 @@ fn format_downtime(seconds: i64) -> String
 pub fn admin() -> Html 
 {
     let (i18n, _) = use_translation();
+    let scope = use_abort_scope();
     
     let metrics = use_state(|| None::<GlobalMetrics>);
     let down_projects = use_state(|| None::<Vec<DownProjectInfo>>);
@@ -38,11 +43,12 @@ pub fn admin() -> Html
         let metrics = metrics.clone();
         let down_projects = down_projects.clone();
         let all_projects = all_projects.clone();
+        let scope = scope.clone();
 
         use_effect_with((), move |_| 
         {
             // Fetch Global Metrics
-            wasm_bindgen_futures::spawn_local(async move 
+            scope.spawn(async move 
             {
                 if let Ok(m) = project_service::get_global_metrics_admin().await 
                 {
@@ -50,7 +56,7 @@ pub fn admin() -> Html
                 }
             });
             // Fetch Down Projects
-            wasm_bindgen_futures::spawn_local(async move 
+            scope.spawn(async move 
             {
                 if let Ok(p) = project_service::get_down_projects_admin().await 
                 {
@@ -58,7 +64,7 @@ pub fn admin() -> Html
                 }
             });
             // Fetch All Projects
-            wasm_bindgen_futures::spawn_local(async move 
+            scope.spawn(async move 
             {
                 if let Ok(p) = project_service::get_all_projects_admin().await 
                 {
@@ -69,6 +75,16 @@ pub fn admin() -> Html
         });
     }
 
+    let mut project_ids: Vec<i32> = all_projects
+        .iter()
+        .flatten()
+        .map(|p| p.id)
+        .chain(down_projects.iter().flatten().map(|p| p.project.id))
+        .collect();
+    project_ids.sort_unstable();
+    project_ids.dedup();
+    let live = use_streamed_project_statuses(project_ids);
+
     html! 
     {
         <div>
@@ -122,9 +138,22 @@ pub fn admin() -> Html
                                                     </Link<AppRoute>>
                                                     <span style="color: var(--color-text-secondary);">{ format!(" (Owner: {})", p.project.owner) }</span>
                                                 </div>
-                                                <span class="status-badge status-stopped">
-                                                    { format!("Down for {}", format_downtime(p.downtime_seconds)) }
-                                                </span>
+                                                {
+                                                    // A project restarted since the list loaded shows its live status
+                                                    match live.statuses.get(p.project.id)
+                                                    {
+                                                        Some(status) if !matches!(status, ContainerStatus::Exited | ContainerStatus::Dead) => html! 
+                                                        {
+                                                            <StatusBadge status={status} />
+                                                        },
+                                                        _ => html! 
+                                                        {
+                                                            <span class={classes!("status-badge", ContainerStatus::Exited.badge_class())}>
+                                                                { format!("Down for {}", format_downtime(p.downtime_seconds)) }
+                                                            </span>
+                                                        },
+                                                    }
+                                                }
                                             </li>
                                         }) 
                                     }
@@ -156,6 +185,7 @@ pub fn admin() -> Html
                                                 <strong>{ &p.name }</strong>
                                             </Link<AppRoute>>
                                             <span class="detail-value">{ &p.owner }</span>
+                                            <StatusBadge status={live.statuses.get(p.id)} />
                                         </li>
                                     })
                                 }
@@ -1,8 +1,29 @@
-use crate::{contexts::user_context::use_user, router::AppRoute, services::auth_service};
+use crate::{contexts::user_context::{use_user, UserAction}, router::AppRoute, services::auth_service, utils::query::{query_pairs, query_param}};
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+/// Route and raw query string of an in-app return path. Anything else is
+/// rejected so the callback cannot be used as an open redirect.
+pub fn return_route(path: &str) -> Option<(AppRoute, String)> 
+{
+    if !path.starts_with('/') || path.starts_with("//") 
+    {
+        return None;
+    }
+    let path = path.split('#').next().unwrap_or(path);
+    let (path, query) = path.split_once('?').unwrap_or((path, ""));
+    AppRoute::recognize(path).map(|route| (route, query.to_string()))
+}
+
+/// Route to land on after login, with the query it was requested with.
+fn redirect_route(query: &str) -> (AppRoute, String) 
+{
+    query_param(query, "redirect")
+        .and_then(|path| return_route(&path))
+        .unwrap_or((AppRoute::Home, String::new()))
+}
+
 #[function_component(AuthCallback)]
 pub fn auth_callback() -> Html 
 {
@@ -21,20 +42,21 @@ pub fn auth_callback() -> Html
         
         move |_| 
         {
-            if let Some(ticket) = location.query_str()
-                .trim_start_matches('?')
-                .split('&')
-                .find_map(|p: &str| p.strip_prefix("ticket=")) 
+            if let Some(ticket) = query_param(location.query_str(), "ticket") 
             {
-                let ticket = ticket.to_string();
+                let (redirect_to, redirect_query) = redirect_route(location.query_str());
                 wasm_bindgen_futures::spawn_local(async move 
                 {
                     match auth_service::validate_ticket(&ticket).await 
                     {
                         Ok(user) => 
                         {
-                            user_context.dispatch(Some(user));
-                            navigator.push(&AppRoute::Home);
+                            user_context.dispatch(UserAction::Set(Some(user)));
+                            let pairs = query_pairs(&redirect_query);
+                            if pairs.is_empty() || navigator.push_with_query(&redirect_to, &pairs).is_err() 
+                            {
+                                navigator.push(&redirect_to);
+                            }
                         }
                         Err(e) => 
                         {
@@ -60,4 +82,30 @@ pub fn auth_callback() -> Html
     {
         html! { <p>{ i18n.t("auth.logging_in") }</p> }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn return_path_keeps_its_query()
+    {
+        let (route, query) = return_route("/projects/7?tab=logs&since=1700000000#top").unwrap();
+        assert!(route == AppRoute::ProjectDashboard { id: 7 });
+        assert_eq!(query, "tab=logs&since=1700000000");
+
+        let (route, query) = return_route("/admin").unwrap();
+        assert!(route == AppRoute::Admin);
+        assert_eq!(query, "");
+    }
+
+    #[test]
+    fn only_in_app_paths_are_accepted()
+    {
+        assert!(return_route("https://evil.example/projects/7").is_none());
+        assert!(return_route("//evil.example/projects/7").is_none());
+        assert!(return_route("projects/7").is_none());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::contexts::config_context::use_config;
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 
@@ -5,6 +6,8 @@ use yew::prelude::*;
 pub fn contact() -> Html 
 {
     let (i18n, _) = use_translation();
+    let config = use_config();
+    let mailto = format!("mailto:{}", config.support_email);
     html! 
     {
         <div style="max-width: 800px; margin: auto;">
@@ -12,7 +15,7 @@ pub fn contact() -> Html
             <p>{ i18n.t("contact.p1") }</p>
             <p>
                 { i18n.t("contact.p2_prefix") }
-                <a href="mailto:dsi@garageisep.com">{ "dsi@garageisep.com" }</a>
+                <a href={mailto}>{ config.support_email.clone() }</a>
                 { "." }
             </p>
         </div>
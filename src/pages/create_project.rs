@@ -2,14 +2,17 @@ use std::collections::{HashMap, HashSet};
 
 use crate::
 {
-    contexts::user_context::use_user,
-    models::project::DeployPayload,
+    contexts::{config_context::use_config, user_context::use_user},
+    hooks::use_abort_scope::use_abort_scope,
+    models::{error_code::ErrorCode, project::DeployPayload},
     router::AppRoute,
     services::
     {
+        api_client::ApiError,
         database_service,
-        project_service::{self, ApiError},
+        project_service,
     },
+    utils::error_message::translate_error,
 };
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
@@ -23,8 +26,6 @@ enum DeployMethod
     Database,
 }
 
-const GITHUB_APP_NAME: &str = "hangar-app";
-
 fn handle_change_textarea(state: UseStateHandle<String>) -> Callback<Event>
 {
     Callback::from(move |e: Event|
@@ -39,8 +40,10 @@ fn handle_change_textarea(state: UseStateHandle<String>) -> Callback<Event>
 pub fn create_project() -> Html
 {
     let (i18n, _) = use_translation();
+    let config = use_config();
     let user_context = use_user();
     let navigator = use_navigator().unwrap();
+    let scope = use_abort_scope();
     let location = use_location().unwrap();
 
     let project_name = use_state(String::new);
@@ -86,6 +89,7 @@ pub fn create_project() -> Html
         let env_vars_str = env_vars_str.clone();
         let volume_path_str = volume_path_str.clone();
         let create_db_with_project = create_db_with_project.clone();
+        let scope = scope.clone();
 
         Callback::from(move |e: SubmitEvent|
         {
@@ -108,7 +112,7 @@ pub fn create_project() -> Html
             let volume_path_str = volume_path_str.clone();
             let create_db_with_project = create_db_with_project.clone();
 
-            wasm_bindgen_futures::spawn_local(async move
+            scope.spawn(async move
             {
                 if *active_method == DeployMethod::Database
                 {
@@ -128,17 +132,11 @@ pub fn create_project() -> Html
                     .collect();
 
                 if let Some(login) = &user_login
+                    && participants_set.contains(login)
                 {
-                    if participants_set.contains(login)
-                    {
-                        error.set(Some(ApiError
-                        {
-                            error_code: "OWNER_CANNOT_BE_PARTICIPANT".to_string(),
-                            details: None,
-                        }));
-                        is_loading.set(false);
-                        return;
-                    }
+                    error.set(Some(ApiError::new(ErrorCode::OwnerCannotBeParticipant, None)));
+                    is_loading.set(false);
+                    return;
                 }
                 let participants: Vec<String> = participants_set.into_iter().collect();
 
@@ -231,28 +229,18 @@ pub fn create_project() -> Html
 
     let render_error = |err: &ApiError|
     {
-        let error_key = format!("errors.{}", err.error_code);
-        let main_message = i18n.t(&error_key);
-        let display_message =
-            if main_message.starts_with("Key '") && main_message.contains(" not found for language ")
-            {
-                i18n.t("errors.DEFAULT")
-            }
-            else
-            {
-                main_message
-            };
+        let display_message = translate_error(err, &i18n);
 
         html!
         {
             <div class="error">
                 <p>{ display_message }</p>
                 {
-                    if err.error_code == "GITHUB_ACCOUNT_NOT_LINKED"
+                    if err.error_code == ErrorCode::GithubAccountNotLinked
                     {
                         let github_app_install_url = format!(
                             "https://github.com/apps/{}/installations/new",
-                            GITHUB_APP_NAME
+                            config.github_app_slug
                         );
                         html!
                         {
@@ -264,7 +252,7 @@ pub fn create_project() -> Html
                             </div>
                         }
                     }
-                    else if err.error_code == "GITHUB_REPO_NOT_ACCESSIBLE"
+                    else if err.error_code == ErrorCode::GithubRepoNotAccessible
                     {
                         let github_installations_url = "https://github.com/settings/installations";
                         html!
@@ -276,7 +264,7 @@ pub fn create_project() -> Html
                             </div>
                         }
                     }
-                    else if err.error_code == "IMAGE_SCAN_FAILED"
+                    else if err.error_code == ErrorCode::ImageScanFailed
                     {
                         if let Some(details) = &err.details
                         {
@@ -3,10 +3,10 @@ use yew_router::prelude::*;
 use i18nrs::yew::use_translation;
 use crate::
 {
-    models::database::DatabaseDetails,
+    contexts::cache_context::use_cached,
+    hooks::use_abort_scope::use_abort_scope,
     services::{database_service, project_service},
     router::AppRoute,
-    services::project_service::ApiError
 };
 
 #[derive(Properties, PartialEq)]
@@ -20,38 +20,18 @@ pub fn database_dashboard(props: &DatabaseDashboardProps) -> Html
 {
     let (i18n, _) = use_translation();
     let navigator = use_navigator().unwrap();
+    let scope = use_abort_scope();
     
-    let db_details = use_state(|| None::<DatabaseDetails>);
-    let projects = use_state(|| vec![]);
-    let error = use_state(|| None::<ApiError>);
+    let db_details = use_cached("/databases/mine".to_string(), database_service::get_my_database);
+    // Without the owned projects, linking is just not possible
+    let projects = use_cached("/projects/owned".to_string(), project_service::get_owned_projects)
+        .and_then(Result::ok)
+        .unwrap_or_default();
     let selected_project_to_link = use_state(String::new);
 
-    {
-        let db_details = db_details.clone();
-        let projects = projects.clone();
-        let error = error.clone();
-
-        use_effect_with((), move |_|
-        {
-            wasm_bindgen_futures::spawn_local(async move 
-            {
-                match database_service::get_my_database().await
-                {
-                    Ok(db) => db_details.set(Some(db)),
-                    Err(e) => error.set(Some(e)),
-                }
-                match project_service::get_owned_projects().await
-                {
-                    Ok(projs) => projects.set(projs),
-                    Err(_) => {}, // Ignore error, linking will just not be possible
-                }
-            });
-            || ()
-        });
-    }
-
     let on_delete =
     {
+        let scope = scope.clone();
         let navigator = navigator.clone();
         let db_id = props.db_id;
         let i18n = i18n.clone();
@@ -61,7 +41,7 @@ pub fn database_dashboard(props: &DatabaseDashboardProps) -> Html
             if web_sys::window().unwrap().confirm_with_message(&i18n.t("database.confirm_delete")).unwrap()
             {
                 let navigator = navigator.clone();
-                wasm_bindgen_futures::spawn_local(async move 
+                scope.spawn(async move 
                 {
                     if database_service::delete_database(db_id).await.is_ok()
                     {
@@ -84,6 +64,7 @@ pub fn database_dashboard(props: &DatabaseDashboardProps) -> Html
 
     let on_link = 
     {
+        let scope = scope.clone();
         let selected_project_to_link = selected_project_to_link.clone();
         let navigator = navigator.clone();
         let db_id = props.db_id;
@@ -95,7 +76,7 @@ pub fn database_dashboard(props: &DatabaseDashboardProps) -> Html
             if let Ok(project_id) = project_id_str.parse::<i32>()
             {
                 let navigator = navigator.clone();
-                wasm_bindgen_futures::spawn_local(async move 
+                scope.spawn(async move 
                 {
                     if database_service::link_database_to_project(project_id, db_id).await.is_ok()
                     {
@@ -106,10 +87,9 @@ pub fn database_dashboard(props: &DatabaseDashboardProps) -> Html
         })
     };
 
-
-    if let Some(db) = &*db_details
+    match &db_details
     {
-        html! 
+        Some(Ok(db)) => html! 
         {
             <div>
                 <h1>{ i18n.t("database.dashboard_title") }</h1>
@@ -146,14 +126,8 @@ pub fn database_dashboard(props: &DatabaseDashboardProps) -> Html
                     <button class="button-danger" onclick={on_delete}>{ i18n.t("database.delete_button") }</button>
                 </div>
             </div>
-        }
-    }
-    else if let Some(e) = &*error
-    {
-        html!{ <p class="error">{ format!("Error loading database: {}", e.error_code) }</p> }
+        },
+        Some(Err(e)) => html!{ <p class="error">{ format!("Error loading database: {}", e.error_code) }</p> },
+        None => html!{ <p>{ i18n.t("common.loading") }</p> },
     }
-    else
-    {
-        html!{ <p>{ i18n.t("common.loading") }</p> }
-    }
-}
\ No newline at end of file
+}
@@ -0,0 +1,21 @@
+use crate::router::AppRoute;
+use i18nrs::yew::use_translation;
+use yew::prelude::*;
+use yew_router::prelude::Link;
+
+#[function_component(Forbidden)]
+pub fn forbidden() -> Html 
+{
+    let (i18n, _) = use_translation();
+
+    html! 
+    {
+        <div class="card error">
+            <h2>{ i18n.t("forbidden.title") }</h2>
+            <p>{ i18n.t("forbidden.message") }</p>
+            <Link<AppRoute> to={AppRoute::Home} classes="button-primary">
+                { i18n.t("common.back_to_home") }
+            </Link<AppRoute>>
+        </div>
+    }
+}
\ No newline at end of file
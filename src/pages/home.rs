@@ -1,21 +1,24 @@
 use crate::
 {
-    contexts::user_context::use_user,
-    models::{database::DatabaseDetails, project::{Project, ProjectSourceType}},
+    components::status_badge::StatusBadge,
+    contexts::{cache_context::use_cached, config_context::use_config, user_context::use_user},
+    hooks::use_project_statuses::{use_project_statuses, ProjectStatuses},
+    models::{container_status::ContainerStatus, database::DatabaseDetails, project::{Project, ProjectSourceType}},
     router::AppRoute,
-    services::{database_service, project_service},
+    services::{auth_service, database_service, project_service},
+    utils::query::query_param,
 };
 use i18nrs::yew::use_translation;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
-const CAS_LOGIN_URL: &str = "https://portail-ovh.isep.fr/cas/login";
-
 #[function_component(Home)]
 pub fn home() -> Html 
 {
     let (i18n, _) = use_translation();
     let user_context = use_user();
+    let location = use_location().unwrap();
+    let config = use_config();
 
     if user_context.user.is_some() 
     {
@@ -23,11 +26,9 @@ pub fn home() -> Html
     } 
     else 
     {
-        let callback_url = format!(
-            "{}/auth/callback",
-            web_sys::window().unwrap().location().origin().unwrap()
-        );
-        let login_url = format!("{}?service={}", CAS_LOGIN_URL, callback_url);
+        // Set by ProtectedRoute when a logged-out user opened a protected page
+        let return_to = query_param(location.query_str(), "redirect");
+        let login_url = auth_service::login_url(&config.cas_login_url, return_to.as_deref());
         html! 
         {
             <div class="home-page" style="text-align: center; margin-top: 10vh; display: flex; flex-direction: column; align-items: center; gap: var(--spacing-lg);">
@@ -45,58 +46,27 @@ fn dashboard() -> Html
 {
     let (i18n, _) = use_translation();
     let user_context = use_user();
-    let owned_projects = use_state(|| None::<Vec<Project>>);
-    let participating_projects = use_state(|| None::<Vec<Project>>);
-    let unlinked_db = use_state(|| None::<DatabaseDetails>);
-
-    {
-        let owned_projects = owned_projects.clone();
-        let participating_projects = participating_projects.clone();
-        let unlinked_db = unlinked_db.clone();
-
-        use_effect_with((), move |_| 
-        {
-            wasm_bindgen_futures::spawn_local(async move 
-            {
-                match project_service::get_owned_projects().await 
-                {
-                    Ok(projects) => owned_projects.set(Some(projects)),
-                    Err(e) => 
-                    {
-                        gloo_console::error!("Failed to fetch owned projects:", e);
-                        owned_projects.set(Some(vec![]));
-                    }
-                }
-                match project_service::get_participating_projects().await 
-                {
-                    Ok(projects) => participating_projects.set(Some(projects)),
-                    Err(e) => 
-                    {
-                        gloo_console::error!("Failed to fetch participating projects:", e);
-                        participating_projects.set(Some(vec![]));
-                    }
-                }
-                let unlinked_db = unlinked_db.clone();
-                wasm_bindgen_futures::spawn_local(async move 
-                {
-                    if let Ok(db) = database_service::get_my_database().await 
-                    {
-                        if db.project_id.is_none() 
-                        {
-                            unlinked_db.set(Some(db));
-                        }
-                    }
-                });
-
-            });
-            || ()
-        });
-    }
+    // A list that fails to load is shown as empty
+    let owned_projects = use_cached("/projects/owned".to_string(), project_service::get_owned_projects)
+        .map(Result::unwrap_or_default);
+    let participating_projects = use_cached("/projects/participations".to_string(), project_service::get_participating_projects)
+        .map(Result::unwrap_or_default);
+    let unlinked_db = use_cached("/databases/mine".to_string(), database_service::get_my_database)
+        .and_then(Result::ok)
+        .filter(|db| db.project_id.is_none());
 
     let welcome_message = i18n
         .t("dashboard.welcome")
         .replace("{name}", &user_context.user.as_ref().unwrap().name);
 
+    let project_ids: Vec<i32> = owned_projects
+        .iter()
+        .chain(participating_projects.iter())
+        .flatten()
+        .map(|p| p.id)
+        .collect();
+    let live = use_project_statuses(project_ids);
+
     html! 
     {
         <div class="dashboard-home">
@@ -110,12 +80,12 @@ fn dashboard() -> Html
 
             <section class="projects-section">
                 <h2>{ i18n.t("dashboard.owned_projects_title") }</h2>
-                <ProjectGrid projects={(*owned_projects).clone()} unlinked_db={(*unlinked_db).clone()} empty_message={i18n.t("dashboard.empty_state_owned")} />
+                <ProjectGrid projects={owned_projects} statuses={live.statuses.clone()} unlinked_db={unlinked_db} empty_message={i18n.t("dashboard.empty_state_owned")} />
             </section>
 
             <section class="projects-section" style="margin-top: var(--spacing-xxl)">
                 <h2>{ i18n.t("dashboard.participating_projects_title") }</h2>
-                <ProjectGrid projects={(*participating_projects).clone()} empty_message={i18n.t("dashboard.empty_state_participating")} />
+                <ProjectGrid projects={participating_projects} statuses={live.statuses.clone()} empty_message={i18n.t("dashboard.empty_state_participating")} />
             </section>
         </div>
     }
@@ -125,6 +95,7 @@ fn dashboard() -> Html
 struct ProjectGridProps 
 {
     projects: Option<Vec<Project>>,
+    statuses: UseReducerHandle<ProjectStatuses>,
     #[prop_or_default] 
     unlinked_db: Option<DatabaseDetails>,
     empty_message: String,
@@ -146,7 +117,7 @@ fn project_grid(props: &ProjectGridProps) -> Html
         (Some(projects), db) => html! 
         {
             <div class="project-grid">
-                { for projects.iter().map(|p| project_card(p, &i18n)) }
+                { for projects.iter().map(|p| project_card(p, props.statuses.get(p.id), &i18n)) }
                 {
                     if let Some(db_details) = db 
                     {
@@ -163,7 +134,7 @@ fn project_grid(props: &ProjectGridProps) -> Html
     }
 }
 
-fn project_card(project: &Project, i18n: &i18nrs::I18n) -> Html 
+fn project_card(project: &Project, status: Option<ContainerStatus>, i18n: &i18nrs::I18n) -> Html 
 {
     let (source_icon, source_title) = match project.source 
     {
@@ -179,6 +150,10 @@ fn project_card(project: &Project, i18n: &i18nrs::I18n) -> Html
                     <h3>{ &project.name }</h3>
                     <img src={source_icon} title={source_title} alt={source_title} style="height: 24px; width: 24px;" />
                 </div>
+                <div class="project-details">
+                    <span>{ i18n.t("common.status") }</span>
+                    <StatusBadge status={status} />
+                </div>
                 <div class="project-details">
                     <span>{ i18n.t("common.owner") }</span>
                     <span class="detail-value">{ &project.owner }</span>
@@ -1,6 +1,7 @@
 pub mod auth_callback;
 pub mod home;
 pub mod not_found;
+pub mod forbidden;
 pub mod create_project;
 pub mod project_dashboard;
 pub mod database_dashboard;
@@ -1,23 +1,47 @@
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::future::Future;
+use std::ops::Range;
 use std::pin::Pin;
+use std::rc::Rc;
 
-use gloo_timers::callback::Interval;
+use futures::StreamExt;
+use gloo_timers::future::TimeoutFuture;
 use i18nrs::yew::use_translation;
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
 use crate::{
-    components::gauge::Gauge,
-    contexts::user_context::use_user,
+    components::{
+        gauge::Gauge,
+        status_badge::StatusBadge,
+        time_series_chart::{ChartPoint, ChartSeries, ChartUnit, Sparkline, TimeSeriesChart},
+    },
+    contexts::{cache_context::use_cached, config_context::use_config, user_context::use_user},
+    hooks::{
+        use_abort_scope::{use_abort_scope, AbortScope},
+        use_polling::use_polling,
+        use_project_statuses::use_project_statuses,
+    },
     models::{
+        container_status::ContainerStatus,
         database::DatabaseDetails,
-        project::{ProjectDetails, ProjectMetrics, ProjectSourceType, UpdateEnvPayload},
+        error_code::ErrorCode,
+        project::{LogQuery, MetricsHistoryRange, MetricsSample, ProjectDetails, ProjectMetrics, ProjectSourceType, UpdateEnvPayload},
     },
     router::AppRoute,
     services::{
+        api_client::ApiError,
         database_service,
-        project_service::{self, ApiError},
+        project_service,
+    },
+    utils::{
+        ansi::{parse_ansi, strip_ansi, AnsiStyle},
+        error_message::translate_error,
+        log_parser::{parse_log_line, LogEntry, LogLevel},
     },
 };
 
@@ -25,9 +49,55 @@ use crate::{
 // CONSTANTS
 // ============================================================================
 
-const STATUS_POLL_INTERVAL_MS: u32 = 5000;
 const METRICS_POLL_INTERVAL_MS: u32 = 3000;
+// Ten minutes of samples at METRICS_POLL_INTERVAL_MS
+const METRICS_LIVE_MAX_SAMPLES: usize = 200;
+// `memory_usage` and `memory_limit` are reported in MiB
+const BYTES_PER_MIB: f64 = 1024.0 * 1024.0;
+const METRICS_PRIMARY_COLOR: &str = "var(--color-primary-accent)";
+const METRICS_SECONDARY_COLOR: &str = "#F5A623";
+const METRICS_RANGES: [(Option<MetricsHistoryRange>, &str); 4] = [
+    (None, "project_dashboard.metrics_range_live"),
+    (Some(MetricsHistoryRange::Hour), "project_dashboard.metrics_range_1h"),
+    (Some(MetricsHistoryRange::Day), "project_dashboard.metrics_range_24h"),
+    (Some(MetricsHistoryRange::Week), "project_dashboard.metrics_range_7d"),
+];
 const RELOAD_DELAY_MS: u32 = 1500;
+const DOWNLOAD_URL_REVOKE_DELAY_MS: u32 = 10000;
+const LOG_STREAM_RECONNECT_DELAY_MS: u32 = 3000;
+const LOG_BUFFER_MAX_LINES: usize = 5000;
+// Must match the `.log-line` height in main.css
+const LOG_ROW_HEIGHT_PX: f64 = 20.0;
+const LOG_OVERSCAN_ROWS: usize = 30;
+const LOG_DEFAULT_VIEWPORT_HEIGHT_PX: f64 = 500.0;
+const LOG_LEVELS: [(LogLevel, &str); 3] = [
+    (LogLevel::Error, "project_dashboard.logs_level_error"),
+    (LogLevel::Warn, "project_dashboard.logs_level_warn"),
+    (LogLevel::Info, "project_dashboard.logs_level_info"),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum LogRange {
+    Tail(u32),
+    LastSeconds(i64),
+    Custom,
+}
+
+const LOG_RANGES: [(LogRange, &str); 6] = [
+    (LogRange::Tail(100), "project_dashboard.logs_range_tail_100"),
+    (LogRange::Tail(1000), "project_dashboard.logs_range_tail_1000"),
+    (LogRange::LastSeconds(15 * 60), "project_dashboard.logs_range_last_15m"),
+    (LogRange::LastSeconds(60 * 60), "project_dashboard.logs_range_last_1h"),
+    (LogRange::LastSeconds(24 * 60 * 60), "project_dashboard.logs_range_last_24h"),
+    (LogRange::Custom, "project_dashboard.logs_range_custom"),
+];
+const DEFAULT_LOG_RANGE: LogRange = LogRange::Tail(1000);
+
+#[derive(Clone, Copy, PartialEq)]
+enum LogExportFormat {
+    Raw,
+    JsonLines,
+}
 
 // ============================================================================
 // TYPE ALIASES
@@ -66,11 +136,6 @@ struct EnvManagerProps {
     on_update: Callback<()>,
 }
 
-#[derive(Properties, PartialEq)]
-struct ProjectStatusProps {
-    project_id: i32,
-}
-
 #[derive(Properties, PartialEq)]
 struct ProjectMetricsDisplayProps {
     project_id: i32,
@@ -87,6 +152,7 @@ struct DatabaseManagerProps {
 #[derive(Properties, PartialEq)]
 struct ProjectControlsProps {
     project_id: i32,
+    status: Option<ContainerStatus>,
     on_update: Callback<()>,
 }
 
@@ -95,171 +161,561 @@ struct ProjectLogsProps {
     project_id: i32,
 }
 
+#[derive(Properties, PartialEq)]
+struct LogToolbarProps {
+    filter: LogFilter,
+    on_change: Callback<LogFilter>,
+    match_count: usize,
+    current_match: Option<usize>,
+    on_jump: Callback<isize>,
+    regex_error: Option<String>,
+    on_export: Callback<LogExportFormat>,
+}
+
 // ============================================================================
 // HELPER FUNCTIONS
 // ============================================================================
 
-fn render_log_line(line: &str) -> Html {
-    let (timestamp, message) = parse_log_line(line);
-    let log_level_class = determine_log_level(message);
+fn render_log_line(
+    line: &VisibleLogLine,
+    matcher: Option<&Regex>,
+    is_current_match: bool,
+    is_expanded: bool,
+    on_toggle_fields: &Callback<u64>,
+) -> Html {
+    let entry = &line.entry;
+    let fields_toggle = (!entry.fields.is_empty()).then(|| {
+        let on_toggle_fields = on_toggle_fields.clone();
+        let id = line.id;
+        html! {
+            <span class="log-fields-toggle" onclick={move |_| on_toggle_fields.emit(id)}>
+                { if is_expanded { "▾" } else { "▸" } }
+            </span>
+        }
+    });
 
     html! {
-        <div class="log-line">
-            <span class="log-timestamp">{ format_timestamp(timestamp) }</span>
-            <span class={classes!("log-message", log_level_class)}>{ message }</span>
-        </div>
+        <>
+            <div class={classes!("log-line", is_current_match.then_some("log-line-current"))}>
+                <span class="log-timestamp">{ format_timestamp(&entry.timestamp) }</span>
+                <div class={classes!("log-message", entry.level.css_class())}>
+                    { for fields_toggle }
+                    { render_log_message(&single_line(&entry.message), &line.highlights) }
+                </div>
+            </div>
+            if is_expanded {
+                {
+                    for entry.fields.iter().map(|(key, value)| {
+                        let value = single_line(value);
+                        let highlights = matcher
+                            .map(|matcher| find_matches(matcher, &strip_ansi(&value)))
+                            .unwrap_or_default();
+                        html! {
+                            <div class="log-field-row">
+                                <span class="log-field-key">{ key }</span>
+                                <span>{ render_log_message(&value, &highlights) }</span>
+                            </div>
+                        }
+                    })
+                }
+            }
+        </>
     }
 }
 
-fn parse_log_line(line: &str) -> (&str, &str) {
-    let parts: Vec<&str> = line.splitn(2, ' ').collect();
-    if parts.len() == 2 && parts[0].ends_with('Z') {
-        (parts[0], parts[1].trim())
+/// Shows a multi-line message or field value (stack traces, ...) on one
+/// row, as virtualization needs every row to be `LOG_ROW_HEIGHT_PX` tall.
+/// Searching runs on this text so highlights line up with what is shown.
+fn single_line(text: &str) -> Cow<'_, str> {
+    if text.contains(['\n', '\r']) {
+        Cow::Owned(text.replace("\r\n", "\n").replace(['\n', '\r'], " ↵ "))
     } else {
-        ("", line)
+        Cow::Borrowed(text)
     }
 }
 
+/// Renders the ANSI-styled segments of a message. `highlights` are byte
+/// ranges into the message with its escape sequences stripped.
+fn render_log_message(message: &str, highlights: &[(usize, usize)]) -> Html {
+    let render_piece = |text: &str, style: &AnsiStyle, is_highlighted: bool| {
+        let css = (!style.is_plain()).then(|| style.to_css());
+        match (is_highlighted, css) {
+            (true, css) => html! { <mark class="log-highlight" style={css}>{ text }</mark> },
+            (false, Some(css)) => html! { <span style={css}>{ text }</span> },
+            (false, None) => html! { text },
+        }
+    };
+
+    let mut pieces = Vec::new();
+    let mut offset = 0;
+    for segment in parse_ansi(message) {
+        let (start, end) = (offset, offset + segment.text.len());
+        offset = end;
+
+        let mut cursor = start;
+        for &(highlight_start, highlight_end) in highlights {
+            if highlight_end <= start || highlight_start >= end {
+                continue;
+            }
+            let (highlight_start, highlight_end) = (highlight_start.max(start), highlight_end.min(end));
+            if highlight_start > cursor {
+                pieces.push(render_piece(&segment.text[cursor - start..highlight_start - start], &segment.style, false));
+            }
+            pieces.push(render_piece(&segment.text[highlight_start - start..highlight_end - start], &segment.style, true));
+            cursor = highlight_end;
+        }
+        if cursor < end {
+            pieces.push(render_piece(&segment.text[cursor - start..], &segment.style, false));
+        }
+    }
+
+    pieces.into_iter().collect::<Html>()
+}
+
 fn format_timestamp(timestamp: &str) -> &str {
     timestamp.split('.').next().unwrap_or(timestamp)
 }
 
-fn determine_log_level(message: &str) -> &'static str {
-    let message_upper = message.to_uppercase();
-    if message_upper.contains("ERROR") || message_upper.contains("FAILED") {
-        "log-error"
-    } else if message_upper.contains("WARN") || message_upper.contains("WARNING") {
-        "log-warn"
-    } else {
-        "log-info"
+/// Both modes ignore case, so switching to regex keeps the same matches.
+fn build_log_matcher(query: &str, use_regex: bool) -> Result<Option<Regex>, regex::Error> {
+    if query.is_empty() {
+        return Ok(None);
     }
+
+    let pattern = if use_regex { query.to_string() } else { regex::escape(query) };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(true)
+        .build()
+        .map(Some)
 }
 
-fn translate_status(status_str: &str, i18n: &i18nrs::I18n) -> String {
-    let key = format!("common.status_{}", status_str);
-    let translation = i18n.t(&key);
+fn find_matches(matcher: &Regex, message: &str) -> Vec<(usize, usize)> {
+    matcher
+        .find_iter(message)
+        .filter(|m| !m.is_empty())
+        .map(|m| (m.start(), m.end()))
+        .collect()
+}
 
-    if is_translation_missing(&translation) {
-        i18n.t("common.status_unknown")
-    } else {
-        translation
+fn parse_datetime_local(value: &str) -> Option<i64> {
+    if value.is_empty() {
+        return None;
     }
+    let millis = js_sys::Date::new(&js_sys::JsString::from(value)).get_time();
+    (!millis.is_nan()).then_some((millis / 1000.0) as i64)
 }
 
-fn translate_error(error: &ApiError, i18n: &i18nrs::I18n) -> String {
-    let error_key = format!("errors.{}", error.error_code);
-    let translation = i18n.t(&error_key);
+/// Whether both bounds of a custom range are set and the end comes first.
+fn is_custom_range_reversed(custom_since: &str, custom_until: &str) -> bool {
+    matches!(
+        (parse_datetime_local(custom_since), parse_datetime_local(custom_until)),
+        (Some(since), Some(until)) if until < since
+    )
+}
 
-    if is_translation_missing(&translation) {
-        i18n.t("errors.DEFAULT")
-    } else {
-        translation
+fn build_log_query(range: LogRange, custom_since: &str, custom_until: &str) -> LogQuery {
+    match range {
+        LogRange::Tail(lines) => LogQuery {
+            tail: Some(lines),
+            ..Default::default()
+        },
+        LogRange::LastSeconds(seconds) => LogQuery {
+            since: Some((js_sys::Date::now() / 1000.0) as i64 - seconds),
+            ..Default::default()
+        },
+        LogRange::Custom => LogQuery {
+            tail: None,
+            since: parse_datetime_local(custom_since),
+            until: parse_datetime_local(custom_until),
+        },
     }
 }
 
-fn is_translation_missing(translation: &str) -> bool {
-    translation.starts_with("Key '") && translation.contains(" not found for language ")
+#[derive(Serialize)]
+struct LogExportEntry<'a> {
+    timestamp: &'a str,
+    level: &'a str,
+    message: &'a str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    fields: BTreeMap<&'a str, &'a str>,
 }
 
-// ============================================================================
-// STATUS & METRICS COMPONENTS
-// ============================================================================
+fn export_log_lines<'a>(entries: impl Iterator<Item = &'a LogEntry>, format: LogExportFormat) -> String {
+    let mut contents = String::new();
+    for entry in entries {
+        match format {
+            LogExportFormat::Raw => contents.push_str(&entry.raw),
+            LogExportFormat::JsonLines => {
+                let message = strip_ansi(&entry.message);
+                let export_entry = LogExportEntry {
+                    timestamp: &entry.timestamp,
+                    level: entry.level.as_str(),
+                    message: &message,
+                    fields: entry
+                        .fields
+                        .iter()
+                        .map(|(key, value)| (key.as_str(), value.as_str()))
+                        .collect(),
+                };
+                contents.push_str(&serde_json::to_string(&export_entry).unwrap_or_default());
+            }
+        }
+        contents.push('\n');
+    }
+    contents
+}
 
-#[function_component(ProjectStatus)]
-fn project_status(props: &ProjectStatusProps) -> Html {
-    let (i18n, _) = use_translation();
-    let status = use_state(|| None::<String>);
+fn download_text_file(file_name: &str, mime_type: &str, contents: &str) -> Result<(), wasm_bindgen::JsValue> {
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| wasm_bindgen::JsValue::from_str("no document"))?;
+    let anchor = document
+        .create_element("a")?
+        .dyn_into::<web_sys::HtmlAnchorElement>()?;
+
+    let parts = js_sys::Array::of1(&js_sys::JsString::from(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    // Nothing below can fail, so the URL is always revoked
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+
+    // Revoking right after the click can cancel the download in some browsers
+    gloo_timers::callback::Timeout::new(DOWNLOAD_URL_REVOKE_DELAY_MS, move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
 
-    {
-        let status = status.clone();
-        let project_id = props.project_id;
+struct VisibleLogLine {
+    id: u64,
+    entry: Rc<LogEntry>,
+    highlights: Vec<(usize, usize)>,
+    is_match: bool,
+}
 
-        use_effect_with(project_id, move |_| {
-            let fetch_status = move || {
-                let status = status.clone();
-                wasm_bindgen_futures::spawn_local(async move {
-                    if let Ok(s) = project_service::get_project_status(project_id).await {
-                        status.set(s);
-                    }
+fn filter_log_lines<'a>(
+    rows: impl Iterator<Item = &'a LogRow>,
+    filter: &LogFilter,
+    matcher: Option<&Regex>,
+) -> Vec<VisibleLogLine> {
+    rows
+        .filter(|row| !filter.hidden_levels.contains(&row.entry.level))
+        .filter_map(|row| {
+            let entry = &row.entry;
+            let highlights = matcher
+                .map(|matcher| find_matches(matcher, &strip_ansi(&single_line(&entry.message))))
+                .unwrap_or_default();
+            let is_match = !highlights.is_empty()
+                || matcher.is_some_and(|matcher| {
+                    entry.fields.iter().any(|(_, value)| matcher.is_match(&strip_ansi(&single_line(value))))
                 });
-            };
+            if filter.only_matching && matcher.is_some() && !is_match {
+                return None;
+            }
+
+            Some(VisibleLogLine {
+                id: row.id,
+                entry: entry.clone(),
+                highlights,
+                is_match,
+            })
+        })
+        .collect()
+}
 
-            fetch_status();
-            let interval = Interval::new(STATUS_POLL_INTERVAL_MS, fetch_status);
+/// The filtered lines together with the top offset of each one (plus the
+/// total height as the last entry), so the rows intersecting the viewport
+/// can be found with a binary search on every scroll.
+struct LogView {
+    matcher: Option<Regex>,
+    regex_error: Option<String>,
+    lines: Vec<VisibleLogLine>,
+    match_positions: Vec<usize>,
+    row_offsets: Vec<f64>,
+}
 
-            move || drop(interval)
-        });
+impl LogView {
+    fn new(rows: &[LogRow], filter: &LogFilter, expanded_rows: &HashSet<u64>) -> Self {
+        let (matcher, regex_error) = match build_log_matcher(&filter.query, filter.use_regex) {
+            Ok(matcher) => (matcher, None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        let lines = filter_log_lines(rows.iter(), filter, matcher.as_ref());
+
+        let match_positions = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.is_match)
+            .map(|(index, _)| index)
+            .collect();
+
+        let mut row_offsets = Vec::with_capacity(lines.len() + 1);
+        let mut offset = 0.0;
+        row_offsets.push(offset);
+        for line in &lines {
+            let field_rows = if expanded_rows.contains(&line.id) { line.entry.fields.len() } else { 0 };
+            offset += (1 + field_rows) as f64 * LOG_ROW_HEIGHT_PX;
+            row_offsets.push(offset);
+        }
+
+        Self {
+            matcher,
+            regex_error,
+            lines,
+            match_positions,
+            row_offsets,
+        }
     }
 
-    let status_class = status
-        .as_ref()
-        .map(|s| format!("status-{}", s))
-        .unwrap_or_else(|| "status-unknown".to_string());
+    fn total_height(&self) -> f64 {
+        self.row_offsets.last().copied().unwrap_or_default()
+    }
 
-    let status_text = status
-        .as_ref()
-        .map(|s| translate_status(s, &i18n))
-        .unwrap_or_else(|| i18n.t("common.loading"));
+    /// Indices of the lines intersecting the viewport, widened by
+    /// `LOG_OVERSCAN_ROWS` on each side.
+    fn visible_range(&self, scroll_top: f64, viewport_height: f64) -> Range<usize> {
+        let first = self
+            .row_offsets
+            .partition_point(|&offset| offset <= scroll_top)
+            .saturating_sub(1);
+        let last = self
+            .row_offsets
+            .partition_point(|&offset| offset < scroll_top + viewport_height);
+
+        first.saturating_sub(LOG_OVERSCAN_ROWS)..(last + LOG_OVERSCAN_ROWS).min(self.lines.len())
+    }
+}
 
-    html! {
-        <span class={classes!("status-badge", status_class)}>
-            { status_text }
-        </span>
+fn metric_points<'a>(
+    samples: impl IntoIterator<Item = &'a MetricsSample>,
+    value_of: fn(&ProjectMetrics) -> f64,
+) -> Vec<ChartPoint> {
+    samples
+        .into_iter()
+        .map(|sample| ChartPoint {
+            timestamp: sample.timestamp,
+            value: value_of(&sample.metrics),
+        })
+        .collect()
+}
+
+// ============================================================================
+// STATUS & METRICS COMPONENTS
+// ============================================================================
+
+/// Samples collected while polling, used for the live chart range.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct LiveMetrics {
+    samples: VecDeque<MetricsSample>,
+}
+
+impl Reducible for LiveMetrics {
+    type Action = MetricsSample;
+
+    fn reduce(self: Rc<Self>, sample: Self::Action) -> Rc<Self> {
+        let mut live = (*self).clone();
+        live.samples.push_back(sample);
+        if live.samples.len() > METRICS_LIVE_MAX_SAMPLES {
+            live.samples.pop_front();
+        }
+        live.into()
     }
 }
 
 #[function_component(ProjectMetricsDisplay)]
 fn project_metrics_display(props: &ProjectMetricsDisplayProps) -> Html {
     let (i18n, _) = use_translation();
-    let metrics = use_state(|| None::<ProjectMetrics>);
+    let config = use_config();
+    let project_id = props.project_id;
+    let polling = use_polling(format!("projects/{}/metrics", project_id), METRICS_POLL_INTERVAL_MS, move || {
+        project_service::get_project_metrics(project_id)
+    });
+    let live_metrics = use_reducer(LiveMetrics::default);
+    let history_range = use_state(|| None::<MetricsHistoryRange>);
+    let history = use_state(|| None::<Result<Vec<MetricsSample>, ApiError>>);
 
-    {
-        let metrics = metrics.clone();
-        let project_id = props.project_id;
+    // Metrics are hidden while polling fails rather than shown stale
+    let metrics = polling.data.clone().filter(|_| polling.error.is_none());
 
-        use_effect_with(project_id, move |_| {
-            let fetch_metrics = move || {
-                let metrics = metrics.clone();
-                wasm_bindgen_futures::spawn_local(async move {
-                    match project_service::get_project_metrics(project_id).await {
-                        Ok(m) => metrics.set(Some(m)),
-                        Err(_) => metrics.set(None),
-                    }
+    {
+        let live_metrics = live_metrics.dispatcher();
+        let metrics = polling.data.clone();
+
+        use_effect_with(polling.updated_at, move |updated_at| {
+            if let (Some(updated_at), Some(metrics)) = (*updated_at, metrics) {
+                live_metrics.dispatch(MetricsSample {
+                    timestamp: (updated_at / 1000.0) as i64,
+                    metrics,
                 });
-            };
+            }
+            || ()
+        });
+    }
 
-            fetch_metrics();
-            let interval = Interval::new(METRICS_POLL_INTERVAL_MS, fetch_metrics);
+    {
+        let history = history.clone();
+        let project_id = props.project_id;
 
-            move || drop(interval)
+        // A new range cancels the request for the previous one
+        use_effect_with((project_id, *history_range), move |&(project_id, range)| {
+            history.set(None);
+            let scope = AbortScope::new();
+            if let Some(range) = range {
+                scope.spawn(async move {
+                    history.set(Some(project_service::get_project_metrics_history(project_id, range).await));
+                });
+            }
+            move || scope.abort()
         });
     }
 
+    let on_range_change = {
+        let history_range = history_range.clone();
+        Callback::from(move |e: Event| {
+            let value = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+            if let Some((range, _)) = value.parse::<usize>().ok().and_then(|index| METRICS_RANGES.get(index)) {
+                history_range.set(*range);
+            }
+        })
+    };
+
+    let live_samples: Vec<MetricsSample>;
+    let samples: Result<&[MetricsSample], Html> = match (&*history_range, &*history) {
+        (None, _) => {
+            live_samples = live_metrics.samples.iter().cloned().collect();
+            Ok(&live_samples)
+        }
+        (Some(_), None) => Err(html! { <p>{ i18n.t("common.loading") }</p> }),
+        (Some(_), Some(Err(e))) => Err(html! {
+            <p class="error">{ i18n.t("project_dashboard.metrics_history_error").replace("{error}", &translate_error(e, &i18n)) }</p>
+        }),
+        (Some(_), Some(Ok(samples))) => Ok(samples),
+    };
+    let show_dates = matches!(*history_range, Some(MetricsHistoryRange::Day | MetricsHistoryRange::Week));
+
+    let charts = match samples {
+        Ok([]) => html! { <p class="placeholder">{ i18n.t("project_dashboard.metrics_history_empty") }</p> },
+        Ok(samples) => {
+            let memory_limit = samples
+                .iter()
+                .map(|sample| sample.metrics.memory_limit * BYTES_PER_MIB)
+                .fold(0.0, f64::max);
+            let series = |label_key: &str, color: &str, value_of: fn(&ProjectMetrics) -> f64| ChartSeries {
+                label: i18n.t(label_key),
+                color: color.to_string(),
+                points: metric_points(samples, value_of),
+            };
+            html! {
+                <div class="metrics-charts">
+                    <TimeSeriesChart
+                        title={i18n.t("project_dashboard.metrics_chart_cpu")}
+                        series={vec![series("project_dashboard.metrics_chart_cpu", METRICS_PRIMARY_COLOR, |m| m.cpu_usage)]}
+                        unit={ChartUnit::Percent}
+                        max_value={100.0}
+                        show_dates={show_dates}
+                    />
+                    <TimeSeriesChart
+                        title={i18n.t("project_dashboard.metrics_chart_memory")}
+                        series={vec![series("project_dashboard.metrics_chart_memory", METRICS_PRIMARY_COLOR, |m| m.memory_usage * BYTES_PER_MIB)]}
+                        unit={ChartUnit::Bytes}
+                        max_value={memory_limit}
+                        show_dates={show_dates}
+                    />
+                    <TimeSeriesChart
+                        title={i18n.t("project_dashboard.metrics_chart_network")}
+                        series={vec![
+                            series("project_dashboard.metrics_chart_network_rx", METRICS_PRIMARY_COLOR, |m| m.network_rx_rate),
+                            series("project_dashboard.metrics_chart_network_tx", METRICS_SECONDARY_COLOR, |m| m.network_tx_rate),
+                        ]}
+                        unit={ChartUnit::BytesPerSecond}
+                        show_dates={show_dates}
+                    />
+                    <TimeSeriesChart
+                        title={i18n.t("project_dashboard.metrics_chart_block")}
+                        series={vec![
+                            series("project_dashboard.metrics_chart_block_read", METRICS_PRIMARY_COLOR, |m| m.block_read_rate),
+                            series("project_dashboard.metrics_chart_block_write", METRICS_SECONDARY_COLOR, |m| m.block_write_rate),
+                        ]}
+                        unit={ChartUnit::BytesPerSecond}
+                        show_dates={show_dates}
+                    />
+                    <TimeSeriesChart
+                        title={i18n.t("project_dashboard.metrics_chart_pids")}
+                        series={vec![series("project_dashboard.metrics_chart_pids", METRICS_PRIMARY_COLOR, |m| m.pids as f64)]}
+                        unit={ChartUnit::Count}
+                        show_dates={show_dates}
+                    />
+                </div>
+            }
+        }
+        Err(message) => message,
+    };
+
     html! {
-        <div class="metrics-grid">
-            {
-                if let Some(m) = &*metrics {
-                    html! {
-                        <>
-                            <Gauge
-                                label="CPU"
-                                value={m.cpu_usage}
-                                max_value={100.0}
-                                unit="%"
-                            />
-                            <Gauge
-                                label="RAM"
-                                value={m.memory_usage}
-                                max_value={m.memory_limit}
-                                unit="MiB"
-                            />
-                        </>
+        <>
+            <div class="metrics-grid">
+                {
+                    if let Some(m) = &metrics {
+                        html! {
+                            <>
+                                <Gauge
+                                    label="CPU"
+                                    value={m.cpu_usage}
+                                    max_value={100.0}
+                                    unit="%"
+                                />
+                                <Gauge
+                                    label="RAM"
+                                    value={m.memory_usage}
+                                    max_value={m.memory_limit}
+                                    unit="MiB"
+                                />
+                                <div class="metric-stats">
+                                    {
+                                        for [
+                                            ("project_dashboard.metrics_chart_network_rx", ChartUnit::BytesPerSecond, m.network_rx_rate, (|m| m.network_rx_rate) as fn(&ProjectMetrics) -> f64),
+                                            ("project_dashboard.metrics_chart_network_tx", ChartUnit::BytesPerSecond, m.network_tx_rate, |m| m.network_tx_rate),
+                                            ("project_dashboard.metrics_chart_block_read", ChartUnit::BytesPerSecond, m.block_read_rate, |m| m.block_read_rate),
+                                            ("project_dashboard.metrics_chart_block_write", ChartUnit::BytesPerSecond, m.block_write_rate, |m| m.block_write_rate),
+                                            ("project_dashboard.metrics_chart_pids", ChartUnit::Count, m.pids as f64, |m| m.pids as f64),
+                                        ]
+                                        .into_iter()
+                                        .map(|(label_key, unit, value, value_of)| html! {
+                                            <div class="metric-stat">
+                                                <span class="metric-stat-label">{ i18n.t(label_key) }</span>
+                                                <Sparkline points={metric_points(&live_metrics.samples, value_of)} unit={unit} />
+                                                <span class="metric-stat-value">{ unit.format(value) }</span>
+                                            </div>
+                                        })
+                                    }
+                                </div>
+                            </>
+                        }
+                    } else {
+                        html! { <p>{ i18n.t("common.loading") }</p> }
                     }
-                } else {
-                    html! { <p>{ i18n.t("common.loading") }</p> }
                 }
+            </div>
+            if config.is_enabled("metrics_history") {
+                <div class="metrics-history-header">
+                    <select class="text-input metrics-range-select" onchange={on_range_change}>
+                        {
+                            for METRICS_RANGES.iter().enumerate().map(|(index, (range, label_key))| html! {
+                                <option value={index.to_string()} selected={*range == *history_range}>
+                                    { i18n.t(label_key) }
+                                </option>
+                            })
+                        }
+                    </select>
+                </div>
             }
-        </div>
+            { charts }
+        </>
     }
 }
 
@@ -270,9 +726,11 @@ fn project_metrics_display(props: &ProjectMetricsDisplayProps) -> Html {
 #[function_component(ProjectControls)]
 fn project_controls(props: &ProjectControlsProps) -> Html {
     let (i18n, _) = use_translation();
+    let scope = use_abort_scope();
     let is_controlling = use_state(|| false);
 
-    let create_control_callback = |action: fn(i32) -> LocalBoxFutureAction<Result<(), String>>| {
+    let create_control_callback = |action: fn(i32) -> LocalBoxFutureAction<Result<(), ApiError>>| {
+        let scope = scope.clone();
         let is_controlling = is_controlling.clone();
         let on_update = props.on_update.clone();
         let project_id = props.project_id;
@@ -282,7 +740,7 @@ fn project_controls(props: &ProjectControlsProps) -> Html {
             let on_update = on_update.clone();
             is_controlling.set(true);
 
-            wasm_bindgen_futures::spawn_local(async move {
+            scope.spawn(async move {
                 if action(project_id).await.is_ok() {
                     gloo_timers::callback::Timeout::new(RELOAD_DELAY_MS, move || {
                         on_update.emit(());
@@ -300,21 +758,27 @@ fn project_controls(props: &ProjectControlsProps) -> Html {
     let on_stop = create_control_callback(|id| Box::pin(project_service::stop_project(id)));
     let on_restart = create_control_callback(|id| Box::pin(project_service::restart_project(id)));
 
+    // Until the first status arrives every action stays available
+    let allowed = |check: fn(ContainerStatus) -> bool| !*is_controlling && props.status.is_none_or(check);
+    let can_start = allowed(ContainerStatus::can_start);
+    let can_stop = allowed(ContainerStatus::can_stop);
+    let can_restart = allowed(ContainerStatus::can_restart);
+
     html! {
         <div class="card" style="margin-top: var(--spacing-lg);">
             <h2>{ i18n.t("project_dashboard.card_title_controls") }</h2>
             <div style="display: flex; gap: var(--spacing-md);">
-                <button class="button-primary" onclick={on_start} disabled={*is_controlling}>
+                <button class="button-primary" onclick={on_start} disabled={!can_start}>
                     { i18n.t("project_dashboard.start_button") }
                 </button>
-                <button class="button-danger" onclick={on_stop} disabled={*is_controlling}>
+                <button class="button-danger" onclick={on_stop} disabled={!can_stop}>
                     { i18n.t("project_dashboard.stop_button") }
                 </button>
                 <button 
                     style="background-color: var(--color-primary-accent-hover)" 
                     class="button-primary" 
                     onclick={on_restart} 
-                    disabled={*is_controlling}
+                    disabled={!can_restart}
                 >
                     { i18n.t("project_dashboard.restart_button") }
                 </button>
@@ -327,17 +791,433 @@ fn project_controls(props: &ProjectControlsProps) -> Html {
 // PROJECT LOGS COMPONENT
 // ============================================================================
 
+#[derive(Clone, Copy, PartialEq)]
+enum LogStreamState {
+    Connecting,
+    Live,
+    Reconnecting,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct LogRow {
+    id: u64,
+    entry: Rc<LogEntry>,
+}
+
+/// `revision` changes whenever `lines` does, so the filtered view can be
+/// memoized without comparing every buffered line.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct LogBuffer {
+    lines: Option<Vec<LogRow>>,
+    pending: Vec<LogRow>,
+    paused: bool,
+    next_id: u64,
+    revision: u64,
+}
+
+enum LogBufferAction {
+    Replace(String),
+    Append(String),
+    SetPaused(bool),
+}
+
+impl LogBuffer {
+    fn parse_lines(&mut self, log_data: &str) -> Vec<LogRow> {
+        log_data
+            .lines()
+            .map(|line| {
+                self.next_id += 1;
+                LogRow {
+                    id: self.next_id,
+                    entry: Rc::new(parse_log_line(line)),
+                }
+            })
+            .collect()
+    }
+
+    fn trim_to_capacity(lines: &mut Vec<LogRow>) {
+        if lines.len() > LOG_BUFFER_MAX_LINES {
+            let overflow = lines.len() - LOG_BUFFER_MAX_LINES;
+            lines.drain(..overflow);
+        }
+    }
+}
+
+impl Reducible for LogBuffer {
+    type Action = LogBufferAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut buffer = (*self).clone();
+
+        match action {
+            LogBufferAction::Replace(log_data) => {
+                buffer.lines = Some(buffer.parse_lines(&log_data));
+                buffer.pending.clear();
+                buffer.revision += 1;
+            }
+            LogBufferAction::Append(chunk) => {
+                let mut rows = buffer.parse_lines(&chunk);
+                let target = if buffer.paused {
+                    &mut buffer.pending
+                } else {
+                    buffer.revision += 1;
+                    buffer.lines.get_or_insert_with(Vec::new)
+                };
+                target.append(&mut rows);
+                Self::trim_to_capacity(target);
+            }
+            LogBufferAction::SetPaused(paused) => {
+                buffer.paused = paused;
+                if !paused && !buffer.pending.is_empty() {
+                    let lines = buffer.lines.get_or_insert_with(Vec::new);
+                    lines.append(&mut buffer.pending);
+                    Self::trim_to_capacity(lines);
+                    buffer.revision += 1;
+                }
+            }
+        }
+
+        buffer.into()
+    }
+}
+
+/// Follows the project's log stream until aborted, reopening the connection
+/// after `LOG_STREAM_RECONNECT_DELAY_MS` whenever it drops.
+async fn follow_project_logs(
+    project_id: i32,
+    logs: UseReducerDispatcher<LogBuffer>,
+    stream_state: UseStateHandle<Option<LogStreamState>>,
+) {
+    loop {
+        match project_service::stream_project_logs(project_id) {
+            Ok(mut lines) => {
+                let mut is_live = false;
+                while let Some(data) = lines.next().await {
+                    if !is_live {
+                        is_live = true;
+                        stream_state.set(Some(LogStreamState::Live));
+                    }
+                    logs.dispatch(LogBufferAction::Append(data));
+                }
+            }
+            Err(e) => gloo_console::error!("Failed to open log stream:", e.to_string()),
+        }
+
+        stream_state.set(Some(LogStreamState::Reconnecting));
+        TimeoutFuture::new(LOG_STREAM_RECONNECT_DELAY_MS).await;
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct LogFilter {
+    query: String,
+    use_regex: bool,
+    hidden_levels: Vec<LogLevel>,
+    only_matching: bool,
+}
+
+#[function_component(LogToolbar)]
+fn log_toolbar(props: &LogToolbarProps) -> Html {
+    let (i18n, _) = use_translation();
+
+    let update_filter = |update: fn(&mut LogFilter, &Event)| {
+        let filter = props.filter.clone();
+        let on_change = props.on_change.clone();
+        move |e: Event| {
+            let mut filter = filter.clone();
+            update(&mut filter, &e);
+            on_change.emit(filter);
+        }
+    };
+
+    let on_query_input = {
+        let filter = props.filter.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |e: InputEvent| {
+            let mut filter = filter.clone();
+            filter.query = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+            on_change.emit(filter);
+        })
+    };
+
+    let on_regex_toggle = Callback::from(update_filter(|filter, e| {
+        filter.use_regex = e.target_unchecked_into::<web_sys::HtmlInputElement>().checked();
+    }));
+
+    let on_only_matching_toggle = Callback::from(update_filter(|filter, e| {
+        filter.only_matching = e.target_unchecked_into::<web_sys::HtmlInputElement>().checked();
+    }));
+
+    let render_level_chip = |&(level, label_key): &(LogLevel, &str)| {
+        let is_active = !props.filter.hidden_levels.contains(&level);
+        let on_toggle = {
+            let filter = props.filter.clone();
+            let on_change = props.on_change.clone();
+            Callback::from(move |_| {
+                let mut filter = filter.clone();
+                if is_active {
+                    filter.hidden_levels.push(level);
+                } else {
+                    filter.hidden_levels.retain(|hidden| *hidden != level);
+                }
+                on_change.emit(filter);
+            })
+        };
+
+        html! {
+            <button
+                class={classes!("log-level-chip", level.css_class(), is_active.then_some("active"))}
+                onclick={on_toggle}
+            >
+                { i18n.t(label_key) }
+            </button>
+        }
+    };
+
+    let on_previous = {
+        let on_jump = props.on_jump.clone();
+        Callback::from(move |_| on_jump.emit(-1))
+    };
+
+    let on_next = {
+        let on_jump = props.on_jump.clone();
+        Callback::from(move |_| on_jump.emit(1))
+    };
+
+    let on_export_raw = {
+        let on_export = props.on_export.clone();
+        Callback::from(move |_| on_export.emit(LogExportFormat::Raw))
+    };
+
+    let on_export_jsonl = {
+        let on_export = props.on_export.clone();
+        Callback::from(move |_| on_export.emit(LogExportFormat::JsonLines))
+    };
+
+    let match_counter = match props.current_match {
+        Some(current) => i18n
+            .t("project_dashboard.logs_match_counter")
+            .replace("{current}", &(current + 1).to_string())
+            .replace("{total}", &props.match_count.to_string()),
+        None if !props.filter.query.is_empty() => i18n.t("project_dashboard.logs_no_matches"),
+        None => String::new(),
+    };
+
+    html! {
+        <div class="logs-toolbar">
+            <div class="logs-toolbar-row">
+                <input
+                    type="search"
+                    class="text-input logs-search-input"
+                    placeholder={i18n.t("project_dashboard.logs_search_placeholder")}
+                    value={props.filter.query.clone()}
+                    oninput={on_query_input}
+                />
+                <button class="button-primary" onclick={on_previous} disabled={props.match_count == 0}>
+                    { "↑" }
+                </button>
+                <button class="button-primary" onclick={on_next} disabled={props.match_count == 0}>
+                    { "↓" }
+                </button>
+                <span class="logs-match-counter">{ match_counter }</span>
+                <button class="button-primary" onclick={on_export_raw}>
+                    { i18n.t("project_dashboard.logs_export_raw") }
+                </button>
+                <button class="button-primary" onclick={on_export_jsonl}>
+                    { i18n.t("project_dashboard.logs_export_jsonl") }
+                </button>
+            </div>
+            <div class="logs-toolbar-row">
+                { for LOG_LEVELS.iter().map(render_level_chip) }
+                <label class="logs-toolbar-option">
+                    <input type="checkbox" checked={props.filter.use_regex} onchange={on_regex_toggle} />
+                    { i18n.t("project_dashboard.logs_regex_label") }
+                </label>
+                <label class="logs-toolbar-option">
+                    <input type="checkbox" checked={props.filter.only_matching} onchange={on_only_matching_toggle} />
+                    { i18n.t("project_dashboard.logs_only_matching_label") }
+                </label>
+            </div>
+            if let Some(error) = &props.regex_error {
+                <p class="error">{ i18n.t("project_dashboard.logs_invalid_regex").replace("{error}", error) }</p>
+            }
+        </div>
+    }
+}
+
 #[function_component(ProjectLogs)]
 fn project_logs(props: &ProjectLogsProps) -> Html {
     let (i18n, _) = use_translation();
-    let logs = use_state(|| None::<String>);
+    let scope = use_abort_scope();
+    let logs = use_reducer(LogBuffer::default);
     let logs_error = use_state(|| None::<String>);
     let are_logs_loading = use_state(|| false);
+    let log_range = use_state(|| DEFAULT_LOG_RANGE);
+    let custom_since = use_state(String::new);
+    let custom_until = use_state(String::new);
+    let is_following = use_state(|| false);
+    let stream_state = use_state(|| None::<LogStreamState>);
+    let filter = use_state(LogFilter::default);
+    let current_match = use_state(|| 0_usize);
+    let expanded_rows = use_state(HashSet::<u64>::new);
+    let scroll_top = use_state(|| 0.0_f64);
+    let viewport_height = use_state(|| LOG_DEFAULT_VIEWPORT_HEIGHT_PX);
+    let container_ref = use_node_ref();
 
-    let on_fetch_logs = {
+    {
+        let logs = logs.dispatcher();
+        let stream_state = stream_state.clone();
+
+        use_effect_with((props.project_id, *is_following), move |&(project_id, is_following)| {
+            let scope = AbortScope::new();
+            if is_following {
+                stream_state.set(Some(LogStreamState::Connecting));
+                scope.spawn(follow_project_logs(project_id, logs, stream_state.clone()));
+            } else {
+                stream_state.set(None);
+            }
+
+            move || scope.abort()
+        });
+    }
+
+    // Keep the newest line in view while following
+    {
+        let container_ref = container_ref.clone();
+        let should_scroll = *is_following && !logs.paused;
+        let line_count = logs.lines.as_ref().map_or(0, Vec::len);
+
+        use_effect_with((line_count, should_scroll), move |&(_, should_scroll)| {
+            if should_scroll
+                && let Some(container) = container_ref.cast::<web_sys::Element>()
+            {
+                container.set_scroll_top(container.scroll_height());
+            }
+            || ()
+        });
+    }
+
+    let view = {
         let logs = logs.clone();
+        use_memo(
+            (logs.revision, (*filter).clone(), (*expanded_rows).clone()),
+            move |(_, filter, expanded_rows)| {
+                LogView::new(logs.lines.as_deref().unwrap_or_default(), filter, expanded_rows)
+            },
+        )
+    };
+
+    let match_positions = &view.match_positions;
+    let current_match_index = (!match_positions.is_empty())
+        .then(|| (*current_match).min(match_positions.len() - 1));
+    let current_line = current_match_index.map(|index| match_positions[index]);
+
+    // Centre the selected match when it changes; the row may not be rendered
+    // yet, so its position comes from the view rather than the DOM
+    {
+        let container_ref = container_ref.clone();
+        let target_offset = current_line.map(|index| view.row_offsets[index]);
+        use_effect_with(target_offset, move |target_offset| {
+            if let Some(offset) = target_offset
+                && let Some(container) = container_ref.cast::<web_sys::Element>()
+            {
+                let viewport_height = container.client_height() as f64;
+                let target = offset - (viewport_height - LOG_ROW_HEIGHT_PX) / 2.0;
+                container.set_scroll_top(target.max(0.0) as i32);
+            }
+            || ()
+        });
+    }
+
+    let on_scroll = {
+        let scroll_top = scroll_top.clone();
+        let viewport_height = viewport_height.clone();
+        Callback::from(move |e: Event| {
+            let container = e.target_unchecked_into::<web_sys::Element>();
+            scroll_top.set(container.scroll_top() as f64);
+            viewport_height.set(container.client_height() as f64);
+        })
+    };
+
+    let on_toggle_fields = {
+        let expanded_rows = expanded_rows.clone();
+        Callback::from(move |id: u64| {
+            let mut rows = (*expanded_rows).clone();
+            if !rows.remove(&id) {
+                rows.insert(id);
+            }
+            expanded_rows.set(rows);
+        })
+    };
+
+    let on_filter_change = {
+        let filter = filter.clone();
+        let current_match = current_match.clone();
+        Callback::from(move |new_filter: LogFilter| {
+            current_match.set(0);
+            filter.set(new_filter);
+        })
+    };
+
+    let on_jump = {
+        let current_match = current_match.clone();
+        let match_count = view.match_positions.len();
+        Callback::from(move |step: isize| {
+            if match_count > 0 {
+                let current = current_match_index.unwrap_or(0) as isize;
+                current_match.set((current + step).rem_euclid(match_count as isize) as usize);
+            }
+        })
+    };
+
+    let on_export = {
+        let view = view.clone();
+        let project_id = props.project_id;
+        Callback::from(move |format: LogExportFormat| {
+            let (extension, mime_type) = match format {
+                LogExportFormat::Raw => ("log", "text/plain"),
+                LogExportFormat::JsonLines => ("jsonl", "application/x-ndjson"),
+            };
+            let exported_at: String = js_sys::Date::new_0().to_iso_string().into();
+            let file_name = format!(
+                "project-{}-{}.{}",
+                project_id,
+                exported_at.split('.').next().unwrap_or_default().replace(':', "-"),
+                extension
+            );
+            let contents = export_log_lines(view.lines.iter().map(|line| line.entry.as_ref()), format);
+
+            if download_text_file(&file_name, mime_type, &contents).is_err() {
+                gloo_console::error!("Failed to export logs");
+            }
+        })
+    };
+
+    let on_range_change = {
+        let log_range = log_range.clone();
+        Callback::from(move |e: Event| {
+            let value = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+            if let Some((range, _)) = value.parse::<usize>().ok().and_then(|index| LOG_RANGES.get(index)) {
+                log_range.set(*range);
+            }
+        })
+    };
+
+    let handle_datetime_change = |state: UseStateHandle<String>| {
+        Callback::from(move |e: Event| {
+            let value = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+            state.set(value);
+        })
+    };
+
+    let on_fetch_logs = {
+        let scope = scope.clone();
+        let logs = logs.dispatcher();
         let logs_error = logs_error.clone();
         let are_logs_loading = are_logs_loading.clone();
+        let log_range = log_range.clone();
+        let custom_since = custom_since.clone();
+        let custom_until = custom_until.clone();
         let project_id = props.project_id;
         let i18n = i18n.clone();
 
@@ -346,18 +1226,21 @@ fn project_logs(props: &ProjectLogsProps) -> Html {
             let logs_error = logs_error.clone();
             let are_logs_loading = are_logs_loading.clone();
             let i18n = i18n.clone();
+            if *log_range == LogRange::Custom && is_custom_range_reversed(&custom_since, &custom_until) {
+                return;
+            }
+            let query = build_log_query(*log_range, &custom_since, &custom_until);
             are_logs_loading.set(true);
             logs_error.set(None);
 
-            wasm_bindgen_futures::spawn_local(async move {
-                match project_service::get_project_logs(project_id).await {
-                    Ok(log_data) => logs.set(Some(log_data)),
+            scope.spawn(async move {
+                match project_service::get_project_logs(project_id, &query).await {
+                    Ok(log_data) => logs.dispatch(LogBufferAction::Replace(log_data)),
                     Err(e) => {
                         let error_message = i18n
                             .t("project_dashboard.logs_error")
-                            .replace("{error}", &e);
+                            .replace("{error}", &translate_error(&e, &i18n));
                         logs_error.set(Some(error_message));
-                        logs.set(None);
                     }
                 }
                 are_logs_loading.set(false);
@@ -365,30 +1248,153 @@ fn project_logs(props: &ProjectLogsProps) -> Html {
         })
     };
 
+    let on_toggle_follow = {
+        let is_following = is_following.clone();
+        let logs = logs.dispatcher();
+        Callback::from(move |_| {
+            logs.dispatch(LogBufferAction::SetPaused(false));
+            is_following.set(!*is_following);
+        })
+    };
+
+    let on_toggle_pause = {
+        let logs = logs.clone();
+        Callback::from(move |_| logs.dispatch(LogBufferAction::SetPaused(!logs.paused)))
+    };
+
+    let stream_indicator = match *stream_state {
+        Some(LogStreamState::Connecting) => Some(("log-stream-connecting", i18n.t("project_dashboard.logs_stream_connecting"))),
+        Some(LogStreamState::Live) => Some(("log-stream-live", i18n.t("project_dashboard.logs_stream_live"))),
+        Some(LogStreamState::Reconnecting) => Some(("log-stream-reconnecting", i18n.t("project_dashboard.logs_stream_reconnecting"))),
+        None => None,
+    };
+
+    let is_range_invalid = *log_range == LogRange::Custom && is_custom_range_reversed(&custom_since, &custom_until);
+
     html! {
         <div class="card" style="margin-top: var(--spacing-lg);">
-            <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: var(--spacing-md);">
-                <h2>{ i18n.t("project_dashboard.card_title_logs") }</h2>
-                <button class="button-primary" onclick={on_fetch_logs} disabled={*are_logs_loading}>
-                    { 
-                        if *are_logs_loading { 
-                            i18n.t("project_dashboard.fetch_logs_loading") 
-                        } else { 
-                            i18n.t("project_dashboard.fetch_logs_button") 
-                        } 
+            <div style="display: flex; justify-content: space-between; align-items: center; flex-wrap: wrap; gap: var(--spacing-md); margin-bottom: var(--spacing-md);">
+                <h2 style="margin-bottom: 0;">{ i18n.t("project_dashboard.card_title_logs") }</h2>
+                <div style="display: flex; align-items: center; flex-wrap: wrap; gap: var(--spacing-sm);">
+                    if let Some((indicator_class, indicator_text)) = stream_indicator {
+                        <span class={classes!("log-stream-indicator", indicator_class)}>{ indicator_text }</span>
                     }
-                </button>
+                    if *is_following {
+                        <button class="button-primary" onclick={on_toggle_pause}>
+                            {
+                                if logs.paused {
+                                    i18n.t("project_dashboard.logs_resume_button")
+                                        .replace("{count}", &logs.pending.len().to_string())
+                                } else {
+                                    i18n.t("project_dashboard.logs_pause_button")
+                                }
+                            }
+                        </button>
+                    }
+                    <button class="button-primary" onclick={on_toggle_follow}>
+                        {
+                            if *is_following {
+                                i18n.t("project_dashboard.logs_unfollow_button")
+                            } else {
+                                i18n.t("project_dashboard.logs_follow_button")
+                            }
+                        }
+                    </button>
+                    <select class="text-input logs-range-select" onchange={on_range_change}>
+                        {
+                            for LOG_RANGES.iter().enumerate().map(|(index, (range, label_key))| html! {
+                                <option value={index.to_string()} selected={*range == *log_range}>
+                                    { i18n.t(label_key) }
+                                </option>
+                            })
+                        }
+                    </select>
+                    <button class="button-primary" onclick={on_fetch_logs} disabled={*are_logs_loading || is_range_invalid}>
+                        { 
+                            if *are_logs_loading { 
+                                i18n.t("project_dashboard.fetch_logs_loading") 
+                            } else { 
+                                i18n.t("project_dashboard.fetch_logs_button") 
+                            } 
+                        }
+                    </button>
+                </div>
             </div>
 
-            <div class="logs-container">
+            if *log_range == LogRange::Custom {
+                <div class="logs-toolbar-row" style="margin-bottom: var(--spacing-sm);">
+                    <label class="logs-toolbar-option">
+                        { i18n.t("project_dashboard.logs_range_since") }
+                        <input
+                            type="datetime-local"
+                            class="text-input"
+                            value={(*custom_since).clone()}
+                            max={(*custom_until).clone()}
+                            onchange={handle_datetime_change(custom_since.clone())}
+                        />
+                    </label>
+                    <label class="logs-toolbar-option">
+                        { i18n.t("project_dashboard.logs_range_until") }
+                        <input
+                            type="datetime-local"
+                            class="text-input"
+                            value={(*custom_until).clone()}
+                            min={(*custom_since).clone()}
+                            onchange={handle_datetime_change(custom_until.clone())}
+                        />
+                    </label>
+                    if is_range_invalid {
+                        <span class="error">{ i18n.t("project_dashboard.logs_range_invalid") }</span>
+                    }
+                </div>
+            }
+
+            if logs.lines.is_some() {
+                <LogToolbar
+                    filter={(*filter).clone()}
+                    on_change={on_filter_change}
+                    match_count={view.match_positions.len()}
+                    current_match={current_match_index}
+                    on_jump={on_jump}
+                    regex_error={view.regex_error.clone()}
+                    on_export={on_export}
+                />
+            }
+
+            <div class="logs-container" ref={container_ref} onscroll={on_scroll}>
                 {
                     if let Some(err_msg) = &*logs_error {
                         html! { <p class="error">{ err_msg }</p> }
-                    } else if let Some(log_data) = &*logs {
-                        if log_data.is_empty() {
+                    } else if let Some(lines) = &logs.lines {
+                        if lines.is_empty() {
                             html! { <div class="placeholder">{ i18n.t("project_dashboard.logs_empty") }</div> }
                         } else {
-                            log_data.lines().map(render_log_line).collect::<Html>()
+                            let range = view.visible_range(*scroll_top, *viewport_height);
+                            let window_offset = view.row_offsets[range.start];
+                            html! {
+                                <div
+                                    class="logs-virtual-spacer"
+                                    style={format!("height: {}px;", view.total_height())}
+                                >
+                                    <div
+                                        class="logs-virtual-window"
+                                        style={format!("transform: translateY({}px);", window_offset)}
+                                    >
+                                        {
+                                            for range.map(|index| {
+                                                let line = &view.lines[index];
+                                                render_log_line(
+                                                    line,
+                                                    view.matcher.as_ref(),
+                                                    Some(index) == current_line,
+                                                    expanded_rows.contains(&line.id),
+                                                    &on_toggle_fields,
+                                                )
+                                            })
+                                        }
+                                    </div>
+                                </div>
+                            }
                         }
                     } else {
                         html! { <div class="placeholder">{ i18n.t("project_dashboard.logs_placeholder") }</div> }
@@ -406,6 +1412,7 @@ fn project_logs(props: &ProjectLogsProps) -> Html {
 #[function_component(ParticipantManager)]
 fn participant_manager(props: &ParticipantManagerProps) -> Html {
     let (i18n, _) = use_translation();
+    let scope = use_abort_scope();
     let new_participant = use_state(String::new);
     let is_loading = use_state(|| false);
     let error = use_state(|| None::<ApiError>);
@@ -419,6 +1426,7 @@ fn participant_manager(props: &ParticipantManagerProps) -> Html {
     };
 
     let on_add = {
+        let scope = scope.clone();
         let is_loading = is_loading.clone();
         let error = error.clone();
         let new_participant = new_participant.clone();
@@ -436,7 +1444,7 @@ fn participant_manager(props: &ParticipantManagerProps) -> Html {
             let error = error.clone();
             let on_update = on_update.clone();
 
-            wasm_bindgen_futures::spawn_local(async move {
+            scope.spawn(async move {
                 match project_service::add_participant(project_id, &participant_id).await {
                     Ok(_) => {
                         new_participant.set(String::new());
@@ -452,6 +1460,7 @@ fn participant_manager(props: &ParticipantManagerProps) -> Html {
     let render_participant = |p: &String| {
         let participant_id = p.clone();
         let on_remove = {
+            let scope = scope.clone();
             let project_id = props.project_id;
             let on_update = props.on_update.clone();
             let i18n = i18n.clone();
@@ -470,7 +1479,7 @@ fn participant_manager(props: &ParticipantManagerProps) -> Html {
                     let on_update = on_update.clone();
                     let participant_id = participant_id.clone();
 
-                    wasm_bindgen_futures::spawn_local(async move {
+                    scope.spawn(async move {
                         if project_service::remove_participant(project_id, &participant_id)
                             .await
                             .is_ok()
@@ -549,6 +1558,7 @@ fn participant_manager(props: &ParticipantManagerProps) -> Html {
 #[function_component(ImageUpdateForm)]
 fn image_update_form(props: &ImageUpdateFormProps) -> Html {
     let (i18n, _) = use_translation();
+    let scope = use_abort_scope();
     let new_image_url = use_state(String::new);
     let is_updating = use_state(|| false);
     let update_error = use_state(|| None::<ApiError>);
@@ -564,13 +1574,13 @@ fn image_update_form(props: &ImageUpdateFormProps) -> Html {
     };
 
     let on_submit = {
+        let scope = scope.clone();
         let project_id = props.project_id;
         let project_name = props.project_name.clone();
         let new_image_url = new_image_url.clone();
         let is_updating = is_updating.clone();
         let update_error = update_error.clone();
         let i18n = i18n.clone();
-        let is_github = is_github;
         let on_update = props.on_update.clone();
 
         Callback::from(move |e: SubmitEvent| {
@@ -598,7 +1608,7 @@ fn image_update_form(props: &ImageUpdateFormProps) -> Html {
                 is_updating.set(true);
                 update_error.set(None);
 
-                wasm_bindgen_futures::spawn_local(async move {
+                scope.spawn(async move {
                     let result = if is_github {
                         project_service::rebuild_project(project_id).await
                     } else {
@@ -687,6 +1697,7 @@ fn image_update_form(props: &ImageUpdateFormProps) -> Html {
 #[function_component(EnvManager)]
 fn env_manager(props: &EnvManagerProps) -> Html {
     let (i18n, _) = use_translation();
+    let scope = use_abort_scope();
 
     let initial_vars_str = props.current_env_vars.as_ref().map_or_else(String::new, |vars| {
         vars.iter()
@@ -713,6 +1724,7 @@ fn env_manager(props: &EnvManagerProps) -> Html {
     };
 
     let on_submit = {
+        let scope = scope.clone();
         let env_vars_str = env_vars_str.clone();
         let is_loading = is_loading.clone();
         let error = error.clone();
@@ -739,7 +1751,7 @@ fn env_manager(props: &EnvManagerProps) -> Html {
             let success = success.clone();
             let on_update = on_update.clone();
 
-            wasm_bindgen_futures::spawn_local(async move {
+            scope.spawn(async move {
                 match project_service::update_env_vars(project_id, &payload).await {
                     Ok(_) => {
                         success.set(true);
@@ -799,16 +1811,18 @@ fn env_manager(props: &EnvManagerProps) -> Html {
 #[function_component(DatabaseManager)]
 fn database_manager(props: &DatabaseManagerProps) -> Html {
     let (i18n, _) = use_translation();
+    let scope = use_abort_scope();
     let project_id = props.project_details.project.id;
     let on_update = props.on_update.clone();
 
     // Scénario 1: Une DB est déjà liée à ce projet
     if let Some(db) = &props.project_details.database {
         let on_unlink = {
+            let scope = scope.clone();
             let on_update = on_update.clone();
             Callback::from(move |_| {
                 let on_update = on_update.clone();
-                wasm_bindgen_futures::spawn_local(async move {
+                scope.spawn(async move {
                     if database_service::unlink_database_from_project(project_id)
                         .await
                         .is_ok()
@@ -820,6 +1834,7 @@ fn database_manager(props: &DatabaseManagerProps) -> Html {
         };
 
         let on_delete_db = {
+            let scope = scope.clone();
             let on_update = on_update.clone();
             let i18n = i18n.clone();
             Callback::from(move |_| {
@@ -829,7 +1844,7 @@ fn database_manager(props: &DatabaseManagerProps) -> Html {
                     .unwrap()
                 {
                     let on_update = on_update.clone();
-                    wasm_bindgen_futures::spawn_local(async move {
+                    scope.spawn(async move {
                         if database_service::delete_linked_database(project_id)
                             .await
                             .is_ok()
@@ -864,39 +1879,41 @@ fn database_manager(props: &DatabaseManagerProps) -> Html {
     }
 
     // Scénario 2: L'utilisateur a une BDD personnelle non liée
-    if let Some(my_db) = &props.my_database {
-        if my_db.project_id.is_none() {
-            let on_link_existing = {
-                let db_id = my_db.id;
+    if let Some(my_db) = &props.my_database
+        && my_db.project_id.is_none()
+    {
+        let on_link_existing = {
+            let scope = scope.clone();
+            let db_id = my_db.id;
+            let on_update = on_update.clone();
+            Callback::from(move |_| {
                 let on_update = on_update.clone();
-                Callback::from(move |_| {
-                    let on_update = on_update.clone();
-                    wasm_bindgen_futures::spawn_local(async move {
-                        if database_service::link_database_to_project(project_id, db_id)
-                            .await
-                            .is_ok()
-                        {
-                            on_update.emit(());
-                        }
-                    });
-                })
-            };
+                scope.spawn(async move {
+                    if database_service::link_database_to_project(project_id, db_id)
+                        .await
+                        .is_ok()
+                    {
+                        on_update.emit(());
+                    }
+                });
+            })
+        };
 
-            return html! {
-                <div>
-                    <p>{ i18n.t("database.unlinked_db_found").replace("{name}", &my_db.database_name) }</p>
-                    <button class="button-primary" onclick={on_link_existing}>
-                        { i18n.t("database.link_this_db_button") }
-                    </button>
-                </div>
-            };
-        }
+        return html! {
+            <div>
+                <p>{ i18n.t("database.unlinked_db_found").replace("{name}", &my_db.database_name) }</p>
+                <button class="button-primary" onclick={on_link_existing}>
+                    { i18n.t("database.link_this_db_button") }
+                </button>
+            </div>
+        };
     }
 
     // Scénario 3: Pas de BDD liée et pas de BDD personnelle disponible
     let error = use_state(|| None::<ApiError>);
     let is_loading = use_state(|| false);
     let on_create_and_link = {
+        let scope = scope.clone();
         let is_loading = is_loading.clone();
         let error = error.clone();
         Callback::from(move |_| {
@@ -906,7 +1923,7 @@ fn database_manager(props: &DatabaseManagerProps) -> Html {
             is_loading.set(true);
             error.set(None);
 
-            wasm_bindgen_futures::spawn_local(async move {
+            scope.spawn(async move {
                 match database_service::create_database().await {
                     Ok(db) => {
                         if database_service::link_database_to_project(project_id, db.id)
@@ -915,10 +1932,7 @@ fn database_manager(props: &DatabaseManagerProps) -> Html {
                         {
                             on_update.emit(());
                         } else {
-                            error.set(Some(ApiError {
-                                error_code: "LINK_FAILED".to_string(),
-                                details: None,
-                            }));
+                            error.set(Some(ApiError::new(ErrorCode::LinkFailed, None)));
                         }
                     }
                     Err(e) => error.set(Some(e)),
@@ -949,6 +1963,7 @@ fn database_manager(props: &DatabaseManagerProps) -> Html {
 struct ProjectInfoProps 
 {
     project_details: ProjectDetails,
+    status: Option<ContainerStatus>,
 }
 
 #[function_component(ProjectInfo)]
@@ -971,7 +1986,7 @@ fn project_info(props: &ProjectInfoProps) -> Html
             </div>
             <p>
                 { i18n.t("common.status") }{ ": " }
-                <ProjectStatus project_id={p.id} />
+                <StatusBadge status={props.status} />
             </p>
             <p>{ format!("{}: {}", i18n.t("common.owner"), p.owner) }</p>
             
@@ -1070,10 +2085,12 @@ struct DangerZoneProps {
 #[function_component(DangerZone)]
 fn danger_zone(props: &DangerZoneProps) -> Html {
     let (i18n, _) = use_translation();
+    let scope = use_abort_scope();
     let navigator = use_navigator().unwrap();
     let deletion_error = use_state(|| None::<String>);
 
     let on_delete = {
+        let scope = scope.clone();
         let project_name = props.project_name.clone();
         let has_linked_db = props.has_linked_database;
         let project_id = props.project_id;
@@ -1102,7 +2119,7 @@ fn danger_zone(props: &DangerZoneProps) -> Html {
                 let deletion_error = deletion_error.clone();
                 let i18n = i18n.clone();
                 
-                wasm_bindgen_futures::spawn_local(async move {
+                scope.spawn(async move {
                     if project_service::purge_project(project_id).await.is_ok() {
                         navigator.push(&AppRoute::Home);
                     } else {
@@ -1137,49 +2154,27 @@ pub fn project_dashboard(props: &ProjectDashboardProps) -> Html {
     let (i18n, _) = use_translation();
     let user_context = use_user();
 
-    let project_details = use_state(|| None::<ProjectDetails>);
-    let my_database = use_state(|| None::<Option<DatabaseDetails>>);
-    let error = use_state(|| None::<String>);
-    let trigger_reload = use_state(|| 0_u32);
-
-    // Fetch project details and database info
-    {
-        let project_details = project_details.clone();
-        let my_database = my_database.clone();
-        let error = error.clone();
-        let project_id = props.project_id;
-
-        use_effect_with((*trigger_reload, project_id), move |_| {
-            wasm_bindgen_futures::spawn_local(async move {
-                match project_service::get_project_details(project_id).await {
-                    Ok(pd) => project_details.set(Some(pd)),
-                    Err(e) => error.set(Some(e)),
-                }
-            });
-
-            let my_database = my_database.clone();
-            wasm_bindgen_futures::spawn_local(async move {
-                match database_service::get_my_database().await {
-                    Ok(db) => my_database.set(Some(Some(db))),
-                    Err(_) => my_database.set(Some(None)),
-                }
-            });
-            || ()
-        });
-    }
+    let project_id = props.project_id;
+    let project_details = use_cached(format!("/projects/{}", project_id), move || {
+        project_service::get_project_details(project_id)
+    });
+    // No database is the same as none we could load
+    let my_database = use_cached("/databases/mine".to_string(), database_service::get_my_database).map(Result::ok);
+    let live_status = use_project_statuses(vec![project_id]);
+    let container_status = live_status.statuses.get(project_id);
 
+    // Mutations invalidate the cached details themselves; only the status
+    // needs a nudge
     let on_update = {
-        let trigger_reload = trigger_reload.clone();
-        Callback::from(move |_| {
-            trigger_reload.set(*trigger_reload + 1);
-        })
+        let refresh_status = live_status.refresh.clone();
+        Callback::from(move |_| refresh_status.emit(()))
     };
 
     // Error state
-    if let Some(e) = &*error {
+    if let Some(Err(e)) = &project_details {
         let error_message = i18n
             .t("project_dashboard.load_error_message")
-            .replace("{error}", e);
+            .replace("{error}", &translate_error(e, &i18n));
         return html! {
             <div class="card error">
                 <h2>{ i18n.t("project_dashboard.access_error_title") }</h2>
@@ -1192,10 +2187,10 @@ pub fn project_dashboard(props: &ProjectDashboardProps) -> Html {
     }
 
     // Loading state
-    let Some(details) = &*project_details else {
+    let Some(Ok(details)) = &project_details else {
         return html! { <div class="loading-spinner">{ i18n.t("common.loading") }</div> };
     };
-    let Some(my_db_option) = &*my_database else {
+    let Some(my_db_option) = &my_database else {
         return html! { <div class="loading-spinner">{ i18n.t("common.loading") }</div> };
     };
 
@@ -1203,18 +2198,18 @@ pub fn project_dashboard(props: &ProjectDashboardProps) -> Html {
     let has_strong_access = user_context
         .user
         .as_ref()
-        .map_or(false, |u| u.is_admin || u.login == p.owner);
+        .is_some_and(|u| u.is_admin || u.login == p.owner);
 
     let has_weak_access = user_context
         .user
         .as_ref()
-        .map_or(false, |u| u.is_admin || u.login == p.owner || details.participants.contains(&u.login));
+        .is_some_and(|u| u.is_admin || u.login == p.owner || details.participants.contains(&u.login));
 
     html! {
         <div>
             <h1>{ i18n.t("project_dashboard.title") }{ format!(": {}", p.name) }</h1>
 
-            <ProjectInfo project_details={details.clone()} />
+            <ProjectInfo project_details={details.clone()} status={container_status} />
 
             <DatabaseInfoCard
                 project_details={details.clone()}
@@ -1224,7 +2219,7 @@ pub fn project_dashboard(props: &ProjectDashboardProps) -> Html {
             />
 
             if has_weak_access {
-                <ProjectControls project_id={p.id} on_update={on_update.clone()} />
+                <ProjectControls project_id={p.id} status={container_status} on_update={on_update.clone()} />
             }
 
             <ProjectLogs project_id={p.id} />
@@ -1270,4 +2265,70 @@ pub fn project_dashboard(props: &ProjectDashboardProps) -> Html {
             
         </div>
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_ignores_case_in_both_modes() {
+        let plain = build_log_matcher("timeout", false).unwrap().unwrap();
+        let regex = build_log_matcher("time(out)?", true).unwrap().unwrap();
+
+        assert_eq!(find_matches(&plain, "Request TIMEOUT after 5s"), vec![(8, 15)]);
+        assert_eq!(find_matches(&regex, "Request TIMEOUT after 5s"), vec![(8, 15)]);
+    }
+
+    fn rows(lines: &[&str]) -> Vec<LogRow> {
+        lines
+            .iter()
+            .enumerate()
+            .map(|(id, line)| LogRow { id: id as u64, entry: Rc::new(parse_log_line(line)) })
+            .collect()
+    }
+
+    fn search(query: &str) -> LogFilter {
+        LogFilter { query: query.to_string(), only_matching: true, ..Default::default() }
+    }
+
+    #[test]
+    fn ansi_colored_field_values_match_on_their_text() {
+        let rows = rows(&[r#"{"level":"info","msg":"checked","disk":"\u001b[31mfull\u001b[0m"}"#]);
+
+        let view = LogView::new(&rows, &search("full"), &HashSet::new());
+        assert_eq!(view.lines.len(), 1);
+        let highlights = find_matches(view.matcher.as_ref().unwrap(), &strip_ansi(&rows[0].entry.fields[0].1));
+        assert_eq!(highlights, vec![(0, 4)]);
+
+        // Escape sequences are not searchable text
+        assert!(LogView::new(&rows, &search("31m"), &HashSet::new()).lines.is_empty());
+    }
+
+    #[test]
+    fn multi_line_field_values_stay_on_one_row() {
+        let rows = rows(&[r#"{"msg":"boom\nsecond line","stack":"Error: boom\r\n    at main.rs:3"}"#]);
+        let expanded = HashSet::from([rows[0].id]);
+
+        let view = LogView::new(&rows, &search("at main"), &expanded);
+        assert_eq!(view.total_height(), 2.0 * LOG_ROW_HEIGHT_PX);
+
+        let message = single_line(&view.lines[0].entry.message);
+        let stack = single_line(&view.lines[0].entry.fields[0].1);
+        assert_eq!(message, "boom ↵ second line");
+        assert_eq!(stack, "Error: boom ↵     at main.rs:3");
+
+        let (start, end) = find_matches(view.matcher.as_ref().unwrap(), &strip_ansi(&stack))[0];
+        assert_eq!(&stack[start..end], "at main");
+    }
+
+    #[test]
+    fn plain_search_escapes_regex_syntax() {
+        let plain = build_log_matcher("a.b", false).unwrap().unwrap();
+
+        assert!(plain.is_match("x A.B y"));
+        assert!(!plain.is_match("axb"));
+        assert!(build_log_matcher("(", true).is_err());
+        assert!(build_log_matcher("", true).unwrap().is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{components::protected_route::ProtectedRoute, pages::{self, admin, create_project, database_dashboard, project_dashboard}};
+use crate::{components::protected_route::ProtectedRoute, models::user::Role, pages::{self, admin, create_project, database_dashboard, project_dashboard}};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -54,7 +54,7 @@ pub fn switch(route: AppRoute) -> Html
         },
         AppRoute::Admin => html! 
         {
-            <ProtectedRoute>
+            <ProtectedRoute required_role={Role::Admin}>
                 <admin::Admin />
             </ProtectedRoute>
         },
@@ -0,0 +1,523 @@
+#[cfg(not(feature = "mock-api"))]
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::future;
+#[cfg(not(feature = "mock-api"))]
+use gloo_events::EventListener;
+use gloo_net::http::{Method, RequestBuilder};
+#[cfg(not(feature = "mock-api"))]
+use gloo_timers::callback::Timeout;
+use gloo_timers::future::TimeoutFuture;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use web_sys::AbortSignal;
+#[cfg(not(feature = "mock-api"))]
+use web_sys::AbortController;
+
+use crate::models::error_code::ErrorCode;
+#[cfg(feature = "mock-api")]
+use crate::services::mock_api;
+
+pub const API_ROOT: &str = "/api";
+
+const DEFAULT_TIMEOUT_MS: u32 = 15_000;
+/// Retries of a read after the first attempt.
+const DEFAULT_MAX_RETRIES: u32 = 2;
+const RETRY_BASE_DELAY_MS: u32 = 500;
+/// Longest `Retry-After` honored by waiting; beyond it the error is returned
+/// so the UI can tell the user when to try again.
+const MAX_RETRY_AFTER_SECONDS: u32 = 5;
+
+type MutationHandler = Rc<dyn Fn(&str)>;
+
+thread_local!
+{
+    static DEFAULT_CLIENT: RefCell<Rc<HangarClient>> = RefCell::new(Rc::new(HangarClient::new(API_ROOT)));
+    static UNAUTHORIZED_HANDLER: RefCell<Option<Rc<dyn Fn()>>> = RefCell::new(None);
+    static MUTATION_HANDLER: RefCell<Option<MutationHandler>> = RefCell::new(None);
+    /// Signal of the task being polled, see `with_abort_signal`.
+    static CURRENT_SIGNAL: RefCell<Option<AbortSignal>> = const { RefCell::new(None) };
+}
+
+/// Error returned by every API call. `error_code` comes from the backend's
+/// error body when there is one, otherwise it describes the client-side
+/// failure (`NetworkError`, `Http(status)`, ...).
+#[derive(Clone, Deserialize, PartialEq, Debug)]
+pub struct ApiError
+{
+    pub error_code: ErrorCode,
+    pub details: Option<String>,
+    /// HTTP status of the response, when one was received.
+    #[serde(skip)]
+    pub status: Option<u16>,
+    /// Seconds to wait before trying again, from the `Retry-After` header.
+    #[serde(skip)]
+    pub retry_after: Option<u32>,
+}
+
+impl ApiError
+{
+    pub fn new(error_code: ErrorCode, details: Option<String>) -> Self
+    {
+        ApiError
+        {
+            error_code,
+            details,
+            status: None,
+            retry_after: None,
+        }
+    }
+
+    fn from_response(response: &RawResponse) -> Self
+    {
+        let error = serde_json::from_str::<ApiError>(&response.body)
+            .unwrap_or_else(|_| ApiError::new(ErrorCode::Http(response.status), None));
+        let error_code = if response.status == 429 { ErrorCode::RateLimited } else { error.error_code };
+
+        ApiError
+        {
+            error_code,
+            status: Some(response.status),
+            retry_after: response.retry_after,
+            ..error
+        }
+    }
+
+    /// Whether sending the same request again may succeed. Timeouts are
+    /// not: each attempt would make the user wait the full timeout again.
+    fn is_transient(&self) -> bool
+    {
+        matches!(self.error_code, ErrorCode::NetworkError | ErrorCode::RateLimited)
+            || matches!(self.status, Some(502..=504))
+    }
+}
+
+impl fmt::Display for ApiError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match &self.details
+        {
+            Some(details) => write!(f, "{}: {}", self.error_code, details),
+            None => write!(f, "{}", self.error_code),
+        }
+    }
+}
+
+/// Status, raw body and `Retry-After` of a response, whatever the transport.
+struct RawResponse
+{
+    status: u16,
+    body: String,
+    retry_after: Option<u32>,
+}
+
+impl RawResponse
+{
+    fn is_success(&self) -> bool
+    {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Thin wrapper over `gloo_net` that prefixes paths with the API base URL,
+/// adds the default headers and turns every failure into an `ApiError`.
+/// Reads that fail transiently are retried with a jittered backoff.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HangarClient
+{
+    base_url: String,
+    default_headers: Vec<(String, String)>,
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    timeout_ms: u32,
+    max_retries: u32,
+}
+
+impl HangarClient
+{
+    pub fn new(base_url: &str) -> Self
+    {
+        HangarClient
+        {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            default_headers: vec![("Accept".to_string(), "application/json".to_string())],
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+            max_retries: DEFAULT_MAX_RETRIES,
+        }
+    }
+
+    /// Time after which a request is abandoned with `RequestTimeout`.
+    pub fn with_timeout(mut self, timeout_ms: u32) -> Self
+    {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self
+    {
+        self.default_headers.retain(|(existing, _)| !existing.eq_ignore_ascii_case(name));
+        self.default_headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    // Only the real transport builds URLs and requests
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    pub fn url(&self, path: &str) -> String
+    {
+        format!("{}{}", self.base_url, path)
+    }
+
+    #[cfg_attr(feature = "mock-api", allow(dead_code))]
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder
+    {
+        self.default_headers
+            .iter()
+            .fold(RequestBuilder::new(&self.url(path)).method(method), |builder, (name, value)| builder.header(name, value))
+    }
+
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError>
+    {
+        self.send(Method::GET, path, &[], None).await
+    }
+
+    pub async fn get_with_query<T, V>(&self, path: &str, query: impl IntoIterator<Item = (&str, V)>) -> Result<T, ApiError>
+    where
+        T: DeserializeOwned,
+        V: AsRef<str>,
+    {
+        let query: Vec<(String, String)> = query
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.as_ref().to_string()))
+            .collect();
+
+        self.send(Method::GET, path, &query, None).await
+    }
+
+    pub async fn post<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError>
+    {
+        self.send(Method::POST, path, &[], None).await
+    }
+
+    pub async fn post_json<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T, ApiError>
+    {
+        self.send(Method::POST, path, &[], Some(Self::to_json(body)?)).await
+    }
+
+    pub async fn put<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError>
+    {
+        self.send(Method::PUT, path, &[], None).await
+    }
+
+    pub async fn put_json<B: Serialize, T: DeserializeOwned>(&self, path: &str, body: &B) -> Result<T, ApiError>
+    {
+        self.send(Method::PUT, path, &[], Some(Self::to_json(body)?)).await
+    }
+
+    pub async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T, ApiError>
+    {
+        self.send(Method::DELETE, path, &[], None).await
+    }
+
+    fn to_json<B: Serialize>(body: &B) -> Result<String, ApiError>
+    {
+        serde_json::to_string(body).map_err(|e| ApiError::new(ErrorCode::ClientSerializationError, Some(e.to_string())))
+    }
+
+    /// Only reads are retried: some PUT endpoints, like rebuild, are not
+    /// idempotent despite their verb.
+    async fn send<T: DeserializeOwned>(&self, method: Method, path: &str, query: &[(String, String)], body: Option<String>) -> Result<T, ApiError>
+    {
+        let is_read = method == Method::GET || method == Method::HEAD;
+        let mut attempt = 0;
+        loop
+        {
+            let result = self.send_once(method.clone(), path, query, body.clone()).await;
+            let delay_ms = match &result
+            {
+                Err(error) if is_read && attempt < self.max_retries && error.is_transient() =>
+                {
+                    retry_delay_ms(error, attempt, js_sys::Math::random())
+                }
+                _ => None,
+            };
+
+            match delay_ms
+            {
+                Some(delay_ms) =>
+                {
+                    attempt += 1;
+                    TimeoutFuture::new(delay_ms).await;
+                }
+                None => return result,
+            }
+        }
+    }
+
+    async fn send_once<T: DeserializeOwned>(&self, method: Method, path: &str, query: &[(String, String)], body: Option<String>) -> Result<T, ApiError>
+    {
+        let is_mutation = method != Method::GET;
+
+        #[cfg(feature = "mock-api")]
+        let response = mock_api::respond(method, path, query, body.as_deref())
+            .await
+            .map(|(status, body)| RawResponse { status, body, retry_after: None })?;
+        #[cfg(not(feature = "mock-api"))]
+        let response = self.fetch(method, path, query, body).await?;
+
+        if is_mutation && response.is_success()
+        {
+            notify_mutation(path);
+        }
+        Self::decode(&response)
+    }
+
+    /// Performs the request and returns the raw response. Requests sent from
+    /// a task wrapped by `with_abort_signal` are cancelled with its signal.
+    #[cfg(not(feature = "mock-api"))]
+    async fn fetch(&self, method: Method, path: &str, query: &[(String, String)], body: Option<String>) -> Result<RawResponse, ApiError>
+    {
+        // Each request gets its own controller so a timeout cancels only it;
+        // it follows the caller's signal for everything else
+        let outer = CURRENT_SIGNAL.with(|current| current.borrow().clone());
+        let controller = AbortController::new().ok();
+        let _follow_outer = match (&outer, &controller)
+        {
+            (Some(outer), Some(controller)) if outer.aborted() =>
+            {
+                controller.abort();
+                None
+            }
+            (Some(outer), Some(controller)) =>
+            {
+                let controller = controller.clone();
+                Some(EventListener::once(outer, "abort", move |_| controller.abort()))
+            }
+            _ => None,
+        };
+        let timed_out = Rc::new(Cell::new(false));
+        let _timeout = controller.clone().map(|controller|
+        {
+            let timed_out = timed_out.clone();
+            Timeout::new(self.timeout_ms, move ||
+            {
+                timed_out.set(true);
+                controller.abort();
+            })
+        });
+
+        let signal = controller.as_ref().map(AbortController::signal);
+        let builder = self
+            .request(method, path)
+            .query(query.iter().map(|(name, value)| (name.as_str(), value.as_str())))
+            .abort_signal(signal.as_ref());
+
+        let request = match body
+        {
+            Some(body) => builder.header("Content-Type", "application/json").body(body),
+            None => builder.build(),
+        }
+        .map_err(|e| ApiError::new(ErrorCode::ClientSerializationError, Some(e.to_string())))?;
+
+        let network_error = |e: gloo_net::Error|
+        {
+            if timed_out.get()
+            {
+                ApiError::new(ErrorCode::RequestTimeout, None)
+            }
+            else if outer.as_ref().is_some_and(AbortSignal::aborted)
+            {
+                ApiError::new(ErrorCode::RequestAborted, None)
+            }
+            else
+            {
+                ApiError::new(ErrorCode::NetworkError, Some(e.to_string()))
+            }
+        };
+        let response = request.send().await.map_err(network_error)?;
+        // Cross-origin, this needs the backend to expose the header
+        let retry_after = response
+            .headers()
+            .get("Retry-After")
+            .and_then(|value| parse_retry_after(&value, js_sys::Date::now()));
+        let body = response.text().await.map_err(network_error)?;
+
+        Ok(RawResponse
+        {
+            status: response.status(),
+            body,
+            retry_after,
+        })
+    }
+
+    /// Decodes a successful body as `T`, or an error body as `ApiError`.
+    /// An empty success body is read as `null`, so endpoints without a
+    /// payload can be typed as `()` or `IgnoredAny`.
+    fn decode<T: DeserializeOwned>(response: &RawResponse) -> Result<T, ApiError>
+    {
+        if !response.is_success()
+        {
+            let error = ApiError::from_response(response);
+            if error.status == Some(401) || error.error_code == ErrorCode::Unauthorized
+            {
+                notify_unauthorized();
+            }
+            return Err(error);
+        }
+
+        let body = if response.body.trim().is_empty() { "null" } else { &response.body };
+
+        serde_json::from_str(body).map_err(|e| ApiError
+        {
+            status: Some(response.status),
+            ..ApiError::new(ErrorCode::ResponseParseError, Some(e.to_string()))
+        })
+    }
+}
+
+/// `Retry-After` is either a number of seconds or an HTTP date.
+#[cfg_attr(feature = "mock-api", allow(dead_code))]
+fn parse_retry_after(value: &str, now_ms: f64) -> Option<u32>
+{
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u32>()
+    {
+        return Some(seconds);
+    }
+
+    let at_ms = js_sys::Date::parse(value);
+    (!at_ms.is_nan()).then(|| ((at_ms - now_ms) / 1000.0).ceil().max(0.0) as u32)
+}
+
+/// Wait before retrying after `error`, or `None` when it is not worth it:
+/// the server asked for a longer pause than we are willing to make.
+/// `random` is in `[0, 1)` and spreads out the delay.
+fn retry_delay_ms(error: &ApiError, attempt: u32, random: f64) -> Option<u32>
+{
+    match error.retry_after
+    {
+        Some(seconds) if seconds > MAX_RETRY_AFTER_SECONDS => None,
+        Some(seconds) => Some(seconds * 1000),
+        None =>
+        {
+            // Half fixed, half random, so clients that failed together do
+            // not retry together
+            let ceiling = RETRY_BASE_DELAY_MS.saturating_mul(2_u32.saturating_pow(attempt));
+            Some(ceiling / 2 + (random * f64::from(ceiling / 2)) as u32)
+        }
+    }
+}
+
+/// The client shared by every service.
+pub fn client() -> Rc<HangarClient>
+{
+    DEFAULT_CLIENT.with(|client| client.borrow().clone())
+}
+
+/// Replaces the shared client, e.g. to point it at another base URL.
+pub fn configure(new_client: HangarClient)
+{
+    DEFAULT_CLIENT.with(|client| *client.borrow_mut() = Rc::new(new_client));
+}
+
+/// Registers the handler called whenever a request is rejected as
+/// unauthorized, before the error is returned to the caller.
+pub fn on_unauthorized(handler: impl Fn() + 'static)
+{
+    UNAUTHORIZED_HANDLER.with(|slot| *slot.borrow_mut() = Some(Rc::new(handler)));
+}
+
+fn notify_unauthorized()
+{
+    // Cloned out first so the handler may itself register a new one
+    if let Some(handler) = UNAUTHORIZED_HANDLER.with(|slot| slot.borrow().clone())
+    {
+        handler();
+    }
+}
+
+/// Runs `task` with `signal` attached to every request it sends, so
+/// aborting the signal cancels them.
+pub async fn with_abort_signal<F: Future>(signal: AbortSignal, task: F) -> F::Output
+{
+    let mut task = std::pin::pin!(task);
+    future::poll_fn(|cx|
+    {
+        // Requests are built synchronously while the task is polled, so the
+        // signal only needs to be visible for the duration of each poll
+        let outer = CURRENT_SIGNAL.with(|current| current.replace(Some(signal.clone())));
+        let poll = task.as_mut().poll(cx);
+        CURRENT_SIGNAL.with(|current| *current.borrow_mut() = outer);
+        poll
+    })
+    .await
+}
+
+/// Registers the handler called with the path of every successful
+/// non-GET request, before its result is returned to the caller.
+pub fn on_mutation(handler: impl Fn(&str) + 'static)
+{
+    MUTATION_HANDLER.with(|slot| *slot.borrow_mut() = Some(Rc::new(handler)));
+}
+
+fn notify_mutation(path: &str)
+{
+    if let Some(handler) = MUTATION_HANDLER.with(|slot| slot.borrow().clone())
+    {
+        handler(path);
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn response(status: u16, body: &str, retry_after: Option<u32>) -> RawResponse
+    {
+        RawResponse { status, body: body.to_string(), retry_after }
+    }
+
+    #[test]
+    fn too_many_requests_is_rate_limited_with_its_delay()
+    {
+        let error = ApiError::from_response(&response(429, r#"{"error_code":"CLIENT_ERROR","details":null}"#, Some(30)));
+
+        assert_eq!(error.error_code, ErrorCode::RateLimited);
+        assert_eq!((error.status, error.retry_after), (Some(429), Some(30)));
+    }
+
+    #[test]
+    fn only_transient_failures_are_retried()
+    {
+        assert!(ApiError::from_response(&response(503, "", None)).is_transient());
+        assert!(!ApiError::new(ErrorCode::RequestTimeout, None).is_transient());
+        assert!(!ApiError::from_response(&response(400, "", None)).is_transient());
+        assert!(!ApiError::new(ErrorCode::RequestAborted, None).is_transient());
+    }
+
+    #[test]
+    fn retry_after_is_waited_out_only_when_short()
+    {
+        let short = ApiError::from_response(&response(503, "", Some(MAX_RETRY_AFTER_SECONDS)));
+        let long = ApiError::from_response(&response(429, "", Some(MAX_RETRY_AFTER_SECONDS + 1)));
+
+        assert_eq!(retry_delay_ms(&short, 0, 0.5), Some(MAX_RETRY_AFTER_SECONDS * 1000));
+        assert_eq!(retry_delay_ms(&long, 0, 0.5), None);
+        assert_eq!(parse_retry_after(" 120 ", 0.0), Some(120));
+    }
+
+    #[test]
+    fn backoff_doubles_with_half_of_it_random()
+    {
+        let error = ApiError::new(ErrorCode::NetworkError, None);
+
+        assert_eq!(retry_delay_ms(&error, 0, 0.0), Some(RETRY_BASE_DELAY_MS / 2));
+        assert_eq!(retry_delay_ms(&error, 0, 0.5), Some(RETRY_BASE_DELAY_MS * 3 / 4));
+        assert_eq!(retry_delay_ms(&error, 2, 0.0), Some(RETRY_BASE_DELAY_MS * 2));
+        assert!(retry_delay_ms(&error, 2, 0.999).unwrap() < RETRY_BASE_DELAY_MS * 4);
+    }
+}
@@ -1,61 +1,69 @@
 use crate::models::user::User;
-use gloo_net::http::Request;
+use crate::services::api_client::{client, ApiError};
+#[cfg(feature = "mock-api")]
+use crate::services::mock_api;
+use serde::de::IgnoredAny;
 use serde::Deserialize;
 
-const API_ROOT: &str = "/api";
-
 #[derive(Deserialize)]
-struct AuthResponse 
+pub struct AuthResponse 
 {
-    user: User,
+    pub user: User,
 }
 
 #[derive(Deserialize)]
-struct MeResponse 
+pub struct MeResponse 
 {
-    user: User,
+    pub user: User,
 }
 
-pub async fn validate_ticket(ticket: &str) -> Result<User, String>
+/// CAS login URL whose service is our callback page. `return_to` is the
+/// in-app path the callback sends the user back to once logged in.
+#[cfg(not(feature = "mock-api"))]
+pub fn login_url(cas_login_url: &str, return_to: Option<&str>) -> String
 {
-    let url = format!("{}/auth/callback?ticket={}", API_ROOT, ticket);
-    
-    let response = Request::get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
+    format!("{}?service={}", cas_login_url, js_sys::encode_uri_component(&callback_url(return_to)))
+}
+
+/// Without CAS, the callback is reached directly with the fake's ticket.
+#[cfg(feature = "mock-api")]
+pub fn login_url(_cas_login_url: &str, return_to: Option<&str>) -> String
+{
+    let callback_url = callback_url(return_to);
+    let separator = if callback_url.contains('?') { '&' } else { '?' };
+    format!("{}{}ticket={}", callback_url, separator, mock_api::MOCK_TICKET)
+}
 
-    if !response.ok() 
+fn callback_url(return_to: Option<&str>) -> String
+{
+    let origin = web_sys::window().unwrap().location().origin().unwrap();
+    match return_to
     {
-        return Err(format!("API Error: status {}", response.status()));
+        Some(path) => format!("{}/auth/callback?redirect={}", origin, js_sys::encode_uri_component(path)),
+        None => format!("{}/auth/callback", origin),
     }
+}
 
-    response
-        .json::<AuthResponse>()
+pub async fn validate_ticket(ticket: &str) -> Result<User, ApiError>
+{
+    client()
+        .get_with_query::<AuthResponse, _>("/auth/callback", [("ticket", ticket)])
         .await
         .map(|r| r.user)
-        .map_err(|e| format!("Deserialization error: {}", e))
 }
 
 
-pub async fn get_current_user() -> Result<User, String> 
+pub async fn get_current_user() -> Result<User, ApiError> 
 {
-    Request::get(&format!("{}/auth/me", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?
-        .json::<MeResponse>()
+    client()
+        .get::<MeResponse>("/auth/me")
         .await
         .map(|r| r.user)
-        .map_err(|_| "Not authenticated or session expired".to_string())
 }
 
 
-pub async fn logout() -> Result<(), String> 
+pub async fn logout() -> Result<(), ApiError> 
 {
-    Request::get(&format!("{}/auth/logout", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
+    client().get::<IgnoredAny>("/auth/logout").await?;
     Ok(())
 }
\ No newline at end of file
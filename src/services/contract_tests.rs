@@ -0,0 +1,166 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::models::container_status::{ContainerStatus, StatusEvent};
+use crate::models::database::{CreateDatabaseResponse, DatabaseDetailsResponse};
+use crate::models::error_code::ErrorCode;
+use crate::models::project::{
+    DeployPayload, DownProjectsResponse, GlobalMetrics, MetricsHistoryResponse, ProjectDetailsResponse, ProjectMetrics, ProjectSourceType, ProjectsResponse, UpdateEnvPayload
+};
+use crate::services::api_client::ApiError;
+use crate::services::auth_service::{AuthResponse, MeResponse};
+use crate::services::project_service::{LogsResponse, StatusResponse};
+
+const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+
+type Decoder = fn(&str) -> Result<(), serde_json::Error>;
+
+fn decodes<T: DeserializeOwned>(json: &str) -> Result<(), serde_json::Error>
+{
+    serde_json::from_str::<T>(json).map(|_| ())
+}
+
+/// Every recorded response and the type its service function reads it as.
+const RESPONSE_FIXTURES: [(&str, Decoder); 19] = [
+    ("auth_callback.json", decodes::<AuthResponse>),
+    ("auth_me.json", decodes::<MeResponse>),
+    ("projects_owned.json", decodes::<ProjectsResponse>),
+    ("projects_participations.json", decodes::<ProjectsResponse>),
+    ("projects_deploy.json", decodes::<ProjectDetailsResponse>),
+    ("project_details.json", decodes::<ProjectDetailsResponse>),
+    ("project_status.json", decodes::<StatusResponse>),
+    ("project_status_missing.json", decodes::<StatusResponse>),
+    ("project_status_event.json", decodes::<StatusEvent>),
+    ("project_logs.json", decodes::<LogsResponse>),
+    ("project_metrics.json", decodes::<ProjectMetrics>),
+    ("project_metrics_legacy.json", decodes::<ProjectMetrics>),
+    ("project_metrics_history.json", decodes::<MetricsHistoryResponse>),
+    ("admin_projects.json", decodes::<ProjectsResponse>),
+    ("admin_projects_down.json", decodes::<DownProjectsResponse>),
+    ("admin_metrics.json", decodes::<GlobalMetrics>),
+    ("databases_mine.json", decodes::<DatabaseDetailsResponse>),
+    ("databases_create.json", decodes::<CreateDatabaseResponse>),
+    ("error.json", decodes::<ApiError>),
+];
+
+fn read_fixture(path: &str) -> String
+{
+    let full_path = format!("{}/{}", FIXTURES_DIR, path);
+    fs::read_to_string(&full_path).unwrap_or_else(|e| panic!("cannot read {}: {}", full_path, e))
+}
+
+fn fixture<T: DeserializeOwned>(path: &str) -> T
+{
+    serde_json::from_str(&read_fixture(path)).unwrap_or_else(|e| panic!("{} does not decode: {}", path, e))
+}
+
+#[test]
+fn every_response_fixture_decodes()
+{
+    for (name, decode) in RESPONSE_FIXTURES
+    {
+        let json = read_fixture(&format!("responses/{}", name));
+        if let Err(e) = decode(&json)
+        {
+            panic!("responses/{} no longer matches its model: {}", name, e);
+        }
+    }
+}
+
+#[test]
+fn every_response_fixture_is_checked()
+{
+    let on_disk: BTreeSet<String> = fs::read_dir(format!("{}/responses", FIXTURES_DIR))
+        .expect("fixtures/responses is missing")
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    let checked: BTreeSet<String> = RESPONSE_FIXTURES.iter().map(|(name, _)| name.to_string()).collect();
+
+    assert_eq!(on_disk, checked, "add new fixtures to RESPONSE_FIXTURES");
+}
+
+#[test]
+fn project_details_flattens_the_project()
+{
+    let details = fixture::<ProjectDetailsResponse>("responses/project_details.json").project;
+
+    assert_eq!(details.project.id, 1);
+    assert_eq!(details.project.source, ProjectSourceType::Github);
+    assert_eq!(details.project.env_vars.unwrap()["NODE_ENV"], "production");
+    assert_eq!(details.participants, ["mmartin"]);
+    assert_eq!(details.database.unwrap().project_id, Some(1));
+}
+
+#[test]
+fn down_projects_flatten_the_project()
+{
+    let down = fixture::<DownProjectsResponse>("responses/admin_projects_down.json").down_projects;
+
+    assert_eq!(down.len(), 1);
+    assert_eq!(down[0].project.name, "bde-bot");
+    assert_eq!(down[0].downtime_seconds, 172_800);
+}
+
+#[test]
+fn status_and_metrics_tolerate_missing_fields()
+{
+    let running = fixture::<StatusResponse>("responses/project_status.json");
+    let missing = fixture::<StatusResponse>("responses/project_status_missing.json");
+    let legacy = fixture::<ProjectMetrics>("responses/project_metrics_legacy.json");
+
+    assert_eq!(running.status, Some(ContainerStatus::Running));
+    assert_eq!(missing.status.unwrap_or_default(), ContainerStatus::Unknown);
+    assert_eq!((legacy.network_rx_rate, legacy.pids), (0.0, 0));
+}
+
+#[test]
+fn error_body_decodes_to_api_error()
+{
+    let error = fixture::<ApiError>("responses/error.json");
+
+    assert_eq!(error.error_code, ErrorCode::ProjectNameTaken);
+    assert!(error.details.is_some());
+}
+
+#[test]
+fn deploy_payload_matches_request_bodies()
+{
+    let direct = DeployPayload
+    {
+        project_name: "new-app".to_string(),
+        image_url: Some("nginx:1.27".to_string()),
+        participants: vec!["mmartin".to_string()],
+        env_vars: Some(HashMap::from([("PORT".to_string(), "8080".to_string())])),
+        persistent_volume_path: Some("/data".to_string()),
+        ..Default::default()
+    };
+    let github = DeployPayload
+    {
+        project_name: "club-website".to_string(),
+        github_repo_url: Some("https://github.com/mmartin/club-website".to_string()),
+        github_branch: Some("main".to_string()),
+        github_root_dir: Some("web".to_string()),
+        create_database: Some(true),
+        ..Default::default()
+    };
+
+    assert_eq!(serde_json::to_value(&direct).unwrap(), fixture::<Value>("requests/deploy_direct.json"));
+    assert_eq!(serde_json::to_value(&github).unwrap(), fixture::<Value>("requests/deploy_github.json"));
+}
+
+#[test]
+fn update_env_payload_matches_request_body()
+{
+    let payload = UpdateEnvPayload
+    {
+        env_vars: HashMap::from([
+            ("DATABASE_URL".to_string(), "postgres://db:5432/app".to_string()),
+            ("NODE_ENV".to_string(), "production".to_string()),
+        ]),
+    };
+
+    assert_eq!(serde_json::to_value(&payload).unwrap(), fixture::<Value>("requests/update_env.json"));
+}
@@ -0,0 +1,890 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use futures::stream::{self, LocalBoxStream, StreamExt};
+use gloo_net::http::Method;
+use gloo_timers::future::TimeoutFuture;
+use js_sys::{Date, Math};
+use serde_json::{json, Value};
+use wasm_bindgen::JsValue;
+
+use crate::models::container_status::{ContainerStatus, StatusEvent};
+use crate::models::error_code::ErrorCode;
+use crate::models::project::{Project, ProjectSourceType};
+use crate::services::api_client::ApiError;
+
+/// Ticket accepted by the fake `/auth/callback`. There is no CAS offline, so
+/// `auth_service::login_url` links straight to the callback with it.
+pub const MOCK_TICKET: &str = "ST-mock";
+
+const MIN_LATENCY_MS: f64 = 120.0;
+const MAX_LATENCY_MS: f64 = 600.0;
+/// Share of start/stop/restart calls answered with a 503.
+const CONTROL_FAILURE_RATE: f64 = 0.05;
+/// Time a container takes to reach the state asked for.
+const TRANSITION_DELAY_MS: f64 = 2500.0;
+const LOG_STREAM_INTERVAL_MS: u32 = 1500;
+const STATUS_STREAM_INTERVAL_MS: u32 = 500;
+const SEEDED_LOG_LINES: usize = 300;
+const MAX_STORED_LOG_LINES: usize = 5000;
+const MEMORY_LIMIT_MIB: f64 = 512.0;
+
+struct MockUser
+{
+    login: &'static str,
+    name: &'static str,
+    email: &'static str,
+    is_admin: bool,
+}
+
+const USERS: [MockUser; 3] = [
+    MockUser { login: "jdupont", name: "Jeanne Dupont", email: "jeanne.dupont@eleve.isep.fr", is_admin: true },
+    MockUser { login: "mmartin", name: "Marc Martin", email: "marc.martin@eleve.isep.fr", is_admin: false },
+    MockUser { login: "lbernard", name: "Lina Bernard", email: "lina.bernard@eleve.isep.fr", is_admin: false },
+];
+
+fn find_user(login: &str) -> Option<&'static MockUser>
+{
+    USERS.iter().find(|user| user.login == login)
+}
+
+/// Error answer: a status plus, when the backend would send one, an error
+/// body with a code.
+struct MockError
+{
+    status: u16,
+    code: Option<ErrorCode>,
+}
+
+fn fail(status: u16, code: ErrorCode) -> MockError
+{
+    MockError { status, code: Some(code) }
+}
+
+fn fail_status(status: u16) -> MockError
+{
+    MockError { status, code: None }
+}
+
+type MockResult = Result<Value, MockError>;
+
+struct MockProject
+{
+    project: Project,
+    participants: Vec<String>,
+    database_id: Option<i32>,
+    status: ContainerStatus,
+    /// State the container settles into once the timestamp (ms) is reached.
+    pending: Option<(ContainerStatus, f64)>,
+    stopped_at: Option<f64>,
+    logs: Vec<String>,
+    cpu_usage: f64,
+    memory_usage: f64,
+}
+
+struct MockDatabase
+{
+    id: i32,
+    owner: String,
+    project_id: Option<i32>,
+}
+
+struct MockState
+{
+    current_user: Option<&'static str>,
+    projects: Vec<MockProject>,
+    databases: Vec<MockDatabase>,
+    next_id: i32,
+}
+
+thread_local!
+{
+    static STATE: RefCell<MockState> = RefCell::new(MockState::seeded());
+}
+
+fn random_between(min: f64, max: f64) -> f64
+{
+    min + Math::random() * (max - min)
+}
+
+fn pick<T: Copy>(items: &[T]) -> T
+{
+    items[(Math::random() * items.len() as f64) as usize % items.len()]
+}
+
+fn iso_date(timestamp_ms: f64) -> String
+{
+    String::from(Date::new(&JsValue::from_f64(timestamp_ms)).to_iso_string())
+}
+
+/// A Docker-style log line mixing the formats the log viewer understands:
+/// plain text, JSON, logfmt and ANSI colours.
+fn fake_log_line(timestamp_ms: f64) -> String
+{
+    let message = match (Math::random() * 8.0) as u32
+    {
+        0 => format!("GET /api/items {} {}ms", pick(&[200, 200, 200, 304, 404]), (random_between(2.0, 80.0)) as u32),
+        1 => format!(
+            r#"{{"level":"info","msg":"request completed","path":"{}","status":200,"duration_ms":{}}}"#,
+            pick(&["/", "/login", "/api/users", "/health"]),
+            (random_between(1.0, 40.0)) as u32
+        ),
+        2 => format!(r#"level=warn msg="slow query" table={} duration={}ms"#, pick(&["users", "events", "sessions"]), (random_between(300.0, 2000.0)) as u32),
+        3 => "\x1b[32mINFO\x1b[0m worker ready, waiting for jobs".to_string(),
+        4 => r#"{"level":"error","msg":"upstream timeout","upstream":"auth","retry":true}"#.to_string(),
+        5 => format!("\x1b[33mWARN\x1b[0m cache miss ratio at {}%", (random_between(10.0, 60.0)) as u32),
+        6 => format!("Processed batch of {} events", (random_between(1.0, 500.0)) as u32),
+        _ => "Healthcheck OK".to_string(),
+    };
+    format!("{} {}", iso_date(timestamp_ms), message)
+}
+
+fn project_name_is_valid(name: &str) -> bool
+{
+    (3..=40).contains(&name.len())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+}
+
+impl MockProject
+{
+    fn new(project: Project, participants: &[&str], status: ContainerStatus) -> Self
+    {
+        let now = Date::now();
+        let logs = (0..SEEDED_LOG_LINES)
+            .map(|i| fake_log_line(now - ((SEEDED_LOG_LINES - i) as f64) * 12_000.0))
+            .collect();
+
+        MockProject
+        {
+            project,
+            participants: participants.iter().map(|p| p.to_string()).collect(),
+            database_id: None,
+            status,
+            pending: None,
+            stopped_at: None,
+            logs,
+            cpu_usage: random_between(2.0, 30.0),
+            memory_usage: random_between(80.0, 300.0),
+        }
+    }
+
+    fn can_view(&self, login: &str) -> bool
+    {
+        self.can_manage(login) || self.participants.iter().any(|p| p == login)
+    }
+
+    fn can_manage(&self, login: &str) -> bool
+    {
+        self.project.owner == login || find_user(login).is_some_and(|u| u.is_admin)
+    }
+
+    fn push_log(&mut self, message: &str)
+    {
+        self.push_line(format!("{} {}", iso_date(Date::now()), message));
+    }
+
+    fn push_line(&mut self, line: String)
+    {
+        self.logs.push(line);
+        if self.logs.len() > MAX_STORED_LOG_LINES
+        {
+            let overflow = self.logs.len() - MAX_STORED_LOG_LINES;
+            self.logs.drain(..overflow);
+        }
+    }
+
+    fn transition_to(&mut self, target: ContainerStatus)
+    {
+        self.pending = Some((target, Date::now() + TRANSITION_DELAY_MS));
+    }
+
+    /// Applies a pending transition once its time has come.
+    fn settle(&mut self, now: f64)
+    {
+        let Some((target, at)) = self.pending
+        else
+        {
+            return;
+        };
+        if now < at
+        {
+            return;
+        }
+
+        self.pending = None;
+        self.status = target;
+        match target
+        {
+            ContainerStatus::Running =>
+            {
+                self.stopped_at = None;
+                self.push_log("\x1b[32mINFO\x1b[0m container started");
+            }
+            ContainerStatus::Exited | ContainerStatus::Dead =>
+            {
+                self.stopped_at = Some(at);
+                self.push_log("Received SIGTERM, shutting down");
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves the CPU and memory readings a little on every call so the
+    /// gauges and charts have something to show.
+    fn sample_metrics(&mut self) -> Value
+    {
+        if self.status != ContainerStatus::Running
+        {
+            return json!({
+                "cpu_usage": 0.0,
+                "memory_usage": 0.0,
+                "memory_limit": MEMORY_LIMIT_MIB,
+                "network_rx_rate": 0.0,
+                "network_tx_rate": 0.0,
+                "block_read_rate": 0.0,
+                "block_write_rate": 0.0,
+                "pids": 0,
+            });
+        }
+
+        self.cpu_usage = (self.cpu_usage + random_between(-6.0, 6.0)).clamp(0.5, 95.0);
+        self.memory_usage = (self.memory_usage + random_between(-15.0, 15.0)).clamp(40.0, MEMORY_LIMIT_MIB * 0.95);
+
+        json!({
+            "cpu_usage": self.cpu_usage,
+            "memory_usage": self.memory_usage,
+            "memory_limit": MEMORY_LIMIT_MIB,
+            "network_rx_rate": random_between(1_000.0, 250_000.0),
+            "network_tx_rate": random_between(500.0, 120_000.0),
+            "block_read_rate": random_between(0.0, 50_000.0),
+            "block_write_rate": random_between(0.0, 200_000.0),
+            "pids": (random_between(8.0, 24.0)) as u64,
+        })
+    }
+}
+
+fn seeded_project(id: i32, name: &str, owner: &str, source: ProjectSourceType, source_url: &str, age_days: f64) -> Project
+{
+    Project
+    {
+        id,
+        name: name.to_string(),
+        owner: owner.to_string(),
+        container_name: format!("hangar-{}", name),
+        source,
+        source_url: source_url.to_string(),
+        source_branch: None,
+        source_root_dir: None,
+        deployed_image_tag: format!("{}:latest", name),
+        created_at: iso_date(Date::now() - age_days * 86_400_000.0),
+        env_vars: Some(HashMap::from([("NODE_ENV".to_string(), "production".to_string())])),
+        persistent_volume_path: None,
+    }
+}
+
+impl MockState
+{
+    fn seeded() -> Self
+    {
+        let now = Date::now();
+
+        let mut portfolio = MockProject::new(
+            seeded_project(1, "portfolio", "jdupont", ProjectSourceType::Github, "https://github.com/jdupont/portfolio", 40.0),
+            &["mmartin"],
+            ContainerStatus::Running,
+        );
+        portfolio.project.source_branch = Some("main".to_string());
+        portfolio.database_id = Some(1);
+
+        let mut hackathon = MockProject::new(
+            seeded_project(2, "hackathon-api", "jdupont", ProjectSourceType::Direct, "ghcr.io/garageisep/hackathon-api:latest", 12.0),
+            &[],
+            ContainerStatus::Exited,
+        );
+        hackathon.stopped_at = Some(now - 3.0 * 3_600_000.0);
+
+        let club_website = MockProject::new(
+            seeded_project(3, "club-website", "mmartin", ProjectSourceType::Github, "https://github.com/mmartin/club-website", 90.0),
+            &["jdupont"],
+            ContainerStatus::Running,
+        );
+
+        let mut bde_bot = MockProject::new(
+            seeded_project(4, "bde-bot", "lbernard", ProjectSourceType::Direct, "docker.io/lbernard/bde-bot:1.2", 5.0),
+            &[],
+            ContainerStatus::Dead,
+        );
+        bde_bot.stopped_at = Some(now - 2.0 * 86_400_000.0);
+
+        MockState
+        {
+            current_user: Some(USERS[0].login),
+            projects: vec![portfolio, hackathon, club_website, bde_bot],
+            databases: vec![MockDatabase { id: 1, owner: "jdupont".to_string(), project_id: Some(1) }],
+            next_id: 5,
+        }
+    }
+
+    fn next_id(&mut self) -> i32
+    {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    fn database_json(&self, id: i32) -> Option<Value>
+    {
+        let database = self.databases.iter().find(|db| db.id == id)?;
+        Some(json!({
+            "id": database.id,
+            "database_name": format!("{}_db", database.owner),
+            "username": database.owner,
+            "password": "mock-password",
+            "host": "db.hangar.local",
+            "port": 5432,
+            "project_id": database.project_id,
+        }))
+    }
+
+    fn project_json(project: &MockProject) -> Value
+    {
+        serde_json::to_value(&project.project).unwrap_or(Value::Null)
+    }
+
+    fn details_json(&self, project: &MockProject) -> Value
+    {
+        let mut details = Self::project_json(project);
+        details["participants"] = json!(project.participants);
+        details["database"] = project
+            .database_id
+            .and_then(|id| self.database_json(id))
+            .unwrap_or(Value::Null);
+        details
+    }
+
+    fn projects_json(&self, keep: impl Fn(&MockProject) -> bool) -> Value
+    {
+        let projects: Vec<Value> = self.projects.iter().filter(|p| keep(p)).map(Self::project_json).collect();
+        json!({ "projects": projects })
+    }
+
+    fn project_index(&self, id: &str, login: &str) -> Result<usize, MockError>
+    {
+        let id: i32 = id.parse().map_err(|_| fail_status(404))?;
+        let index = self
+            .projects
+            .iter()
+            .position(|p| p.project.id == id)
+            .ok_or_else(|| fail(404, ErrorCode::NotFound))?;
+
+        if self.projects[index].can_view(login) { Ok(index) } else { Err(fail_status(403)) }
+    }
+
+    fn managed_project_index(&self, id: &str, login: &str) -> Result<usize, MockError>
+    {
+        let index = self.project_index(id, login)?;
+        if self.projects[index].can_manage(login) { Ok(index) } else { Err(fail_status(403)) }
+    }
+
+    fn handle(&mut self, method: &str, segments: &[&str], query: &[(String, String)], body: Option<&Value>) -> MockResult
+    {
+        let now = Date::now();
+        self.projects.iter_mut().for_each(|p| p.settle(now));
+
+        if let ["auth", rest @ ..] = segments
+        {
+            return self.handle_auth(method, rest, query);
+        }
+
+        let login = self.current_user.ok_or_else(|| fail(401, ErrorCode::Unauthorized))?;
+
+        match (method, segments)
+        {
+            ("GET", ["projects", "owned"]) => Ok(self.projects_json(|p| p.project.owner == login)),
+            ("GET", ["projects", "participations"]) => Ok(self.projects_json(|p| p.participants.iter().any(|l| l == login))),
+            ("POST", ["projects", "deploy"]) => self.deploy(login, body.unwrap_or(&Value::Null)),
+            ("GET", ["projects", id]) =>
+            {
+                let index = self.project_index(id, login)?;
+                Ok(json!({ "project": self.details_json(&self.projects[index]) }))
+            }
+            ("DELETE", ["projects", id]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                let removed = self.projects.remove(index);
+                self.databases
+                    .iter_mut()
+                    .filter(|db| db.project_id == Some(removed.project.id))
+                    .for_each(|db| db.project_id = None);
+                Ok(Value::Null)
+            }
+            ("GET", ["projects", id, "status"]) =>
+            {
+                let index = self.project_index(id, login)?;
+                Ok(json!({ "status": self.projects[index].status }))
+            }
+            ("POST", ["projects", id, action @ ("start" | "stop" | "restart")]) =>
+            {
+                let index = self.project_index(id, login)?;
+                if Math::random() < CONTROL_FAILURE_RATE
+                {
+                    return Err(fail_status(503));
+                }
+
+                let project = &mut self.projects[index];
+                match *action
+                {
+                    "start" => project.transition_to(ContainerStatus::Running),
+                    "stop" => project.transition_to(ContainerStatus::Exited),
+                    _ =>
+                    {
+                        project.status = ContainerStatus::Restarting;
+                        project.push_log("Restart requested");
+                        project.transition_to(ContainerStatus::Running);
+                    }
+                }
+                Ok(Value::Null)
+            }
+            ("GET", ["projects", id, "logs"]) =>
+            {
+                let index = self.project_index(id, login)?;
+                Ok(json!({ "logs": self.logs(index, query) }))
+            }
+            ("GET", ["projects", id, "metrics"]) =>
+            {
+                let index = self.project_index(id, login)?;
+                Ok(self.projects[index].sample_metrics())
+            }
+            ("GET", ["projects", id, "metrics", "history"]) =>
+            {
+                let index = self.project_index(id, login)?;
+                let range = query.iter().find(|(name, _)| name == "range").map(|(_, value)| value.as_str());
+                Ok(json!({ "samples": self.metrics_history(index, range) }))
+            }
+            ("PUT", ["projects", id, "image"]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                let image = body
+                    .and_then(|b| b["new_image_url"].as_str())
+                    .filter(|image| !image.trim().is_empty() && !image.contains(char::is_whitespace))
+                    .ok_or_else(|| fail(400, ErrorCode::InvalidImageUrl))?;
+                if image.contains("vulnerable")
+                {
+                    return Err(fail(422, ErrorCode::ImageScanFailed));
+                }
+
+                let project = &mut self.projects[index];
+                project.project.source_url = image.to_string();
+                project.project.deployed_image_tag = image.to_string();
+                project.status = ContainerStatus::Restarting;
+                project.push_log(&format!("Pulling {}", image));
+                project.transition_to(ContainerStatus::Running);
+                Ok(Value::Null)
+            }
+            ("PUT", ["projects", id, "rebuild"]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                let project = &mut self.projects[index];
+                project.status = ContainerStatus::Restarting;
+                project.push_log("Rebuilding image from source");
+                project.transition_to(ContainerStatus::Running);
+                Ok(Value::Null)
+            }
+            ("PUT", ["projects", id, "env"]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                let env_vars: HashMap<String, String> = body
+                    .and_then(|b| serde_json::from_value(b["env_vars"].clone()).ok())
+                    .ok_or_else(|| fail(400, ErrorCode::ClientError))?;
+
+                let project = &mut self.projects[index];
+                project.project.env_vars = Some(env_vars);
+                project.status = ContainerStatus::Restarting;
+                project.transition_to(ContainerStatus::Running);
+                Ok(Value::Null)
+            }
+            ("POST", ["projects", id, "participants"]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                let participant = body
+                    .and_then(|b| b["participant_id"].as_str())
+                    .map(str::trim)
+                    .filter(|p| !p.is_empty())
+                    .ok_or_else(|| fail(400, ErrorCode::ClientError))?;
+
+                let project = &mut self.projects[index];
+                if participant == project.project.owner
+                {
+                    return Err(fail(400, ErrorCode::OwnerCannotBeParticipant));
+                }
+                if !project.participants.iter().any(|p| p == participant)
+                {
+                    project.participants.push(participant.to_string());
+                }
+                Ok(Value::Null)
+            }
+            ("DELETE", ["projects", id, "participants", participant]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                self.projects[index].participants.retain(|p| p != participant);
+                Ok(Value::Null)
+            }
+            ("PUT", ["projects", id, "database", db_id]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                let project_id = self.projects[index].project.id;
+                let database = self
+                    .databases
+                    .iter_mut()
+                    .find(|db| db.id.to_string() == *db_id && db.owner == login)
+                    .ok_or_else(|| fail(404, ErrorCode::NotFound))?;
+                if database.project_id.is_some()
+                {
+                    return Err(fail(409, ErrorCode::LinkFailed));
+                }
+
+                database.project_id = Some(project_id);
+                self.projects[index].database_id = Some(database.id);
+                Ok(Value::Null)
+            }
+            ("DELETE", ["projects", id, "database"]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                let project_id = self.projects[index].project.id;
+                self.projects[index].database_id = None;
+                self.databases
+                    .iter_mut()
+                    .filter(|db| db.project_id == Some(project_id))
+                    .for_each(|db| db.project_id = None);
+                Ok(Value::Null)
+            }
+            ("DELETE", ["projects", id, "database", "delete"]) =>
+            {
+                let index = self.managed_project_index(id, login)?;
+                let database_id = self.projects[index].database_id.take().ok_or_else(|| fail(404, ErrorCode::NotFound))?;
+                self.databases.retain(|db| db.id != database_id);
+                Ok(Value::Null)
+            }
+            ("GET", ["databases", "mine"]) =>
+            {
+                let id = self
+                    .databases
+                    .iter()
+                    .find(|db| db.owner == login)
+                    .map(|db| db.id)
+                    .ok_or_else(|| fail(404, ErrorCode::NotFound))?;
+                Ok(json!({ "database": self.database_json(id) }))
+            }
+            ("POST", ["databases"]) =>
+            {
+                let id = self.create_database(login)?;
+                Ok(json!({ "database": self.database_json(id) }))
+            }
+            ("DELETE", ["databases", id]) =>
+            {
+                let position = self
+                    .databases
+                    .iter()
+                    .position(|db| db.id.to_string() == *id && db.owner == login)
+                    .ok_or_else(|| fail(404, ErrorCode::NotFound))?;
+                if self.databases[position].project_id.is_some()
+                {
+                    return Err(fail(409, ErrorCode::DeleteFailed));
+                }
+                self.databases.remove(position);
+                Ok(Value::Null)
+            }
+            ("GET", ["admin", ..]) if !find_user(login).is_some_and(|u| u.is_admin) => Err(fail_status(403)),
+            ("GET", ["admin", "projects"]) => Ok(self.projects_json(|_| true)),
+            ("GET", ["admin", "projects", "down"]) =>
+            {
+                let down: Vec<Value> = self
+                    .projects
+                    .iter()
+                    .filter_map(|p|
+                    {
+                        let stopped_at = p.stopped_at?;
+                        let mut info = Self::project_json(p);
+                        info["stopped_at"] = json!(iso_date(stopped_at));
+                        info["downtime_seconds"] = json!(((now - stopped_at) / 1000.0) as i64);
+                        Some(info)
+                    })
+                    .collect();
+                Ok(json!({ "down_projects": down }))
+            }
+            ("GET", ["admin", "metrics"]) =>
+            {
+                let running = self.projects.iter().filter(|p| p.status == ContainerStatus::Running);
+                Ok(json!({
+                    "total_projects": self.projects.len(),
+                    "running_containers": running.clone().count(),
+                    "total_cpu_usage": running.clone().map(|p| p.cpu_usage).sum::<f64>(),
+                    "total_memory_usage_mb": running.map(|p| p.memory_usage).sum::<f64>(),
+                }))
+            }
+            _ => Err(fail_status(404)),
+        }
+    }
+
+    fn handle_auth(&mut self, method: &str, segments: &[&str], query: &[(String, String)]) -> MockResult
+    {
+        match (method, segments)
+        {
+            ("GET", ["me"]) => self.current_user_json().ok_or_else(|| fail(401, ErrorCode::Unauthorized)),
+            ("GET", ["callback"]) =>
+            {
+                let ticket = query.iter().find(|(name, _)| name == "ticket").map(|(_, value)| value.as_str());
+                if ticket != Some(MOCK_TICKET)
+                {
+                    return Err(fail(401, ErrorCode::Unauthorized));
+                }
+                self.current_user = Some(USERS[0].login);
+                self.current_user_json().ok_or_else(|| fail_status(500))
+            }
+            ("GET", ["logout"]) =>
+            {
+                self.current_user = None;
+                Ok(Value::Null)
+            }
+            _ => Err(fail_status(404)),
+        }
+    }
+
+    fn current_user_json(&self) -> Option<Value>
+    {
+        let user = find_user(self.current_user?)?;
+        Some(json!({
+            "user": {
+                "login": user.login,
+                "name": user.name,
+                "email": user.email,
+                "is_admin": user.is_admin,
+            }
+        }))
+    }
+
+    fn create_database(&mut self, login: &str) -> Result<i32, MockError>
+    {
+        if self.databases.iter().any(|db| db.owner == login)
+        {
+            return Err(fail(409, ErrorCode::DatabaseAlreadyExists));
+        }
+
+        let id = self.next_id();
+        self.databases.push(MockDatabase { id, owner: login.to_string(), project_id: None });
+        Ok(id)
+    }
+
+    fn deploy(&mut self, login: &str, body: &Value) -> MockResult
+    {
+        let name = body["project_name"].as_str().unwrap_or_default().trim();
+        if !project_name_is_valid(name)
+        {
+            return Err(fail(400, ErrorCode::InvalidProjectName));
+        }
+        if self.projects.iter().any(|p| p.project.name == name)
+        {
+            return Err(fail(409, ErrorCode::ProjectNameTaken));
+        }
+
+        let participants: Vec<&str> = body["participants"]
+            .as_array()
+            .map(|list| list.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        if participants.contains(&login)
+        {
+            return Err(fail(400, ErrorCode::OwnerCannotBeParticipant));
+        }
+
+        let (source, source_url) = match (body["image_url"].as_str(), body["github_repo_url"].as_str())
+        {
+            (Some(image), _) if image.trim().is_empty() || image.contains(char::is_whitespace) => return Err(fail(400, ErrorCode::InvalidImageUrl)),
+            (Some(image), _) if image.contains("vulnerable") => return Err(fail(422, ErrorCode::ImageScanFailed)),
+            (Some(image), _) => (ProjectSourceType::Direct, image.to_string()),
+            (None, Some(repo)) if repo.contains("private") => return Err(fail(403, ErrorCode::GithubRepoNotAccessible)),
+            (None, Some(repo)) => (ProjectSourceType::Github, repo.to_string()),
+            (None, None) => return Err(fail(400, ErrorCode::ClientError)),
+        };
+
+        let database_id = if body["create_database"].as_bool() == Some(true)
+        {
+            Some(self.create_database(login)?)
+        }
+        else
+        {
+            None
+        };
+
+        let id = self.next_id();
+        let mut project = seeded_project(id, name, login, source, &source_url, 0.0);
+        project.source_branch = body["github_branch"].as_str().map(String::from);
+        project.source_root_dir = body["github_root_dir"].as_str().map(String::from);
+        project.persistent_volume_path = body["persistent_volume_path"].as_str().map(String::from);
+        project.env_vars = serde_json::from_value(body["env_vars"].clone()).ok();
+
+        let mut mock_project = MockProject::new(project, &participants, ContainerStatus::Created);
+        mock_project.logs.clear();
+        mock_project.push_log(&format!("Deploying {}", source_url));
+        mock_project.transition_to(ContainerStatus::Running);
+        mock_project.database_id = database_id;
+        if let Some(database) = self.databases.iter_mut().find(|db| Some(db.id) == database_id)
+        {
+            database.project_id = Some(id);
+        }
+
+        let details = self.details_json(&mock_project);
+        self.projects.push(mock_project);
+        Ok(json!({ "project": details }))
+    }
+
+    fn logs(&self, index: usize, query: &[(String, String)]) -> String
+    {
+        let param = |name: &str| query.iter().find(|(key, _)| key == name).and_then(|(_, value)| value.parse::<f64>().ok());
+        // `since` and `until` are Unix seconds, compared against the line's
+        // own timestamp prefix
+        let in_range = |line: &&String|
+        {
+            let timestamp = line
+                .split_once(' ')
+                .map(|(ts, _)| Date::parse(ts) / 1000.0)
+                .unwrap_or_default();
+            param("since").is_none_or(|since| timestamp >= since) && param("until").is_none_or(|until| timestamp <= until)
+        };
+
+        let lines: Vec<&String> = self.projects[index].logs.iter().filter(in_range).collect();
+        let skip = param("tail").map_or(0, |tail| lines.len().saturating_sub(tail as usize));
+        lines[skip..].iter().map(|line| line.as_str()).collect::<Vec<_>>().join("\n")
+    }
+
+    /// Smooth daily curve plus noise, so longer ranges look like real usage.
+    fn metrics_history(&self, index: usize, range: Option<&str>) -> Vec<Value>
+    {
+        let (count, step_seconds) = match range
+        {
+            Some("7d") => (168, 3_600.0),
+            Some("24h") => (96, 900.0),
+            _ => (60, 60.0),
+        };
+        let now = Date::now() / 1000.0;
+        let project = &self.projects[index];
+
+        (0..count)
+            .map(|i|
+            {
+                let timestamp = now - (count - i) as f64 * step_seconds;
+                let daily = ((timestamp / 86_400.0) * std::f64::consts::TAU).sin();
+                let cpu = (project.cpu_usage + daily * 10.0 + random_between(-3.0, 3.0)).clamp(0.5, 95.0);
+                let memory = (project.memory_usage + daily * 40.0 + random_between(-10.0, 10.0)).clamp(40.0, MEMORY_LIMIT_MIB);
+                json!({
+                    "timestamp": timestamp as i64,
+                    "cpu_usage": cpu,
+                    "memory_usage": memory,
+                    "memory_limit": MEMORY_LIMIT_MIB,
+                    "network_rx_rate": (60_000.0 + daily * 40_000.0 + random_between(0.0, 20_000.0)).max(0.0),
+                    "network_tx_rate": (30_000.0 + daily * 20_000.0 + random_between(0.0, 10_000.0)).max(0.0),
+                    "block_read_rate": random_between(0.0, 30_000.0),
+                    "block_write_rate": random_between(0.0, 90_000.0),
+                    "pids": (random_between(10.0, 20.0)) as u64,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Answers an API call from the in-memory state after a random delay.
+/// Returns the status and raw body, like a real response.
+pub async fn respond(method: Method, path: &str, query: &[(String, String)], body: Option<&str>) -> Result<(u16, String), ApiError>
+{
+    TimeoutFuture::new(random_between(MIN_LATENCY_MS, MAX_LATENCY_MS) as u32).await;
+
+    let body = body
+        .map(serde_json::from_str::<Value>)
+        .transpose()
+        .map_err(|e| ApiError::new(ErrorCode::ClientSerializationError, Some(e.to_string())))?;
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    let result = STATE.with(|state| state.borrow_mut().handle(method.as_str(), &segments, query, body.as_ref()));
+
+    Ok(match result
+    {
+        Ok(value) => (200, value.to_string()),
+        Err(MockError { status, code: Some(code) }) => (status, json!({ "error_code": code, "details": null }).to_string()),
+        Err(MockError { status, code: None }) => (status, String::new()),
+    })
+}
+
+/// Fake `/logs/stream`: a new line every `LOG_STREAM_INTERVAL_MS` while the
+/// container runs. The stream ends if the project disappears.
+pub fn log_stream(project_id: i32) -> LocalBoxStream<'static, String>
+{
+    stream::unfold((), move |_| async move
+    {
+        loop
+        {
+            TimeoutFuture::new(LOG_STREAM_INTERVAL_MS).await;
+
+            let line = STATE.with(|state|
+            {
+                let mut state = state.borrow_mut();
+                let now = Date::now();
+                let project = state.projects.iter_mut().find(|p| p.project.id == project_id)?;
+                project.settle(now);
+
+                let line = (project.status == ContainerStatus::Running).then(|| fake_log_line(now));
+                if let Some(line) = &line
+                {
+                    project.push_line(line.clone());
+                }
+                Some(line)
+            });
+
+            match line
+            {
+                None => return None,
+                Some(Some(line)) => return Some((line, ())),
+                Some(None) => continue,
+            }
+        }
+    })
+    .boxed_local()
+}
+
+/// Fake `/projects/status/stream`: the status of every visible project in
+/// `project_ids` at once, then each change as transitions settle.
+pub fn status_stream(project_ids: Vec<i32>) -> LocalBoxStream<'static, StatusEvent>
+{
+    stream::unfold((HashMap::new(), true), move |(mut sent, first)| 
+    {
+        let project_ids = project_ids.clone();
+        async move
+        {
+            if !first
+            {
+                TimeoutFuture::new(STATUS_STREAM_INTERVAL_MS).await;
+            }
+
+            let changes: Vec<StatusEvent> = STATE.with(|state|
+            {
+                let mut state = state.borrow_mut();
+                let now = Date::now();
+                let login = state.current_user.unwrap_or_default();
+                state
+                    .projects
+                    .iter_mut()
+                    .filter(|p| project_ids.contains(&p.project.id) && p.can_view(login))
+                    .filter_map(|p|
+                    {
+                        p.settle(now);
+                        let changed = sent.insert(p.project.id, p.status) != Some(p.status);
+                        changed.then_some(StatusEvent { project_id: p.project.id, status: p.status })
+                    })
+                    .collect()
+            });
+
+            Some((stream::iter(changes), (sent, false)))
+        }
+    })
+    .flatten()
+    .boxed_local()
+}
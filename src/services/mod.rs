@@ -1,3 +1,9 @@
+pub mod api_client;
+#[cfg(feature = "mock-api")]
+pub mod mock_api;
 pub mod auth_service;
 pub mod project_service;
-pub mod database_service;
\ No newline at end of file
+pub mod database_service;
+
+#[cfg(test)]
+mod contract_tests;
\ No newline at end of file
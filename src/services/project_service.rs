@@ -1,22 +1,33 @@
 use crate::models::project::{
-    DeployPayload, DownProjectInfo, DownProjectsResponse, GlobalMetrics, Project, ProjectDetails, ProjectDetailsResponse, ProjectMetrics, ProjectsResponse, UpdateEnvPayload
+    DeployPayload, DownProjectInfo, DownProjectsResponse, GlobalMetrics, LogQuery, MetricsHistoryRange, MetricsHistoryResponse, MetricsSample, Project, ProjectDetails, ProjectDetailsResponse, ProjectMetrics, ProjectsResponse, UpdateEnvPayload
 };
-use gloo_net::http::Request;
+use crate::models::container_status::{ContainerStatus, StatusEvent};
+#[cfg(not(feature = "mock-api"))]
+use crate::models::error_code::ErrorCode;
+use crate::services::api_client::{client, ApiError, HangarClient};
+#[cfg(feature = "mock-api")]
+use crate::services::mock_api;
+use futures::stream::LocalBoxStream;
+#[cfg(not(feature = "mock-api"))]
+use futures::{future, stream, StreamExt};
+#[cfg(not(feature = "mock-api"))]
+use gloo_net::eventsource::futures::EventSource;
+use serde::de::IgnoredAny;
 use serde::{Deserialize, Serialize};
 
-const API_ROOT: &str = "/api";
+/// Deploys, image updates and rebuilds pull and scan the image before
+/// answering, which takes far longer than any other call.
+const BUILD_TIMEOUT_MS: u32 = 5 * 60 * 1000;
 
-#[derive(Clone, Deserialize, PartialEq, Debug)]
-pub struct ApiError 
+fn build_client() -> HangarClient
 {
-    pub error_code: String,
-    pub details: Option<String>,
+    client().as_ref().clone().with_timeout(BUILD_TIMEOUT_MS)
 }
 
 #[derive(Deserialize)]
 pub struct StatusResponse 
 {
-    pub status: Option<String>,
+    pub status: Option<ContainerStatus>,
 }
 
 #[derive(Deserialize)]
@@ -37,241 +48,164 @@ struct ParticipantPayload
     participant_id: String,
 }
 
-async fn parse_simple_error_response(response: gloo_net::http::Response) -> String 
+pub async fn get_owned_projects() -> Result<Vec<Project>, ApiError> 
 {
-    #[derive(Deserialize)]
-    struct SimpleErrorResponse 
-    {
-        error_code: String,
-    }
-
-    if let Ok(error_body) = response.json::<SimpleErrorResponse>().await 
-    {
-        error_body.error_code
-    } 
-    else 
-    {
-        format!("HTTP_ERROR_{}", response.status())
-    }
-}
-
-pub async fn parse_detailed_error_response(response: gloo_net::http::Response) -> ApiError 
-{
-    if let Ok(error_body) = response.json::<ApiError>().await 
-    {
-        error_body
-    } 
-    else 
-    {
-        ApiError 
-        {
-            error_code: format!("HTTP_ERROR_{}", response.status()),
-            details: None,
-        }
-    }
-}
-
-pub async fn get_owned_projects() -> Result<Vec<Project>, String> 
-{
-    let response = Request::get(&format!("{}/projects/owned", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
-        .json::<ProjectsResponse>()
+    client()
+        .get::<ProjectsResponse>("/projects/owned")
         .await
         .map(|r| r.projects)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
-pub async fn get_participating_projects() -> Result<Vec<Project>, String> 
+pub async fn get_participating_projects() -> Result<Vec<Project>, ApiError> 
 {
-    let response = Request::get(&format!("{}/projects/participations", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
-        .json::<ProjectsResponse>()
+    client()
+        .get::<ProjectsResponse>("/projects/participations")
         .await
         .map(|r| r.projects)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
 pub async fn deploy_project(payload: DeployPayload) -> Result<ProjectDetails, ApiError> 
 {
-    let response = Request::post(&format!("{}/projects/deploy", API_ROOT))
-        .json(&payload)
-        .map_err(|_| ApiError 
-        {
-            error_code: "CLIENT_SERIALIZATION_ERROR".to_string(),
-            details: None,
-        })?
-        .send()
-        .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
-
-    if !response.ok() 
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
-
-    response
-        .json::<ProjectDetailsResponse>()
+    build_client()
+        .post_json::<_, ProjectDetailsResponse>("/projects/deploy", &payload)
         .await
         .map(|pr| pr.project)
-        .map_err(|e| ApiError 
-        {
-            error_code: "RESPONSE_PARSE_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })
 }
 
-pub async fn purge_project(project_id: i32) -> Result<(), String> 
+pub async fn purge_project(project_id: i32) -> Result<(), ApiError> 
 {
-    let response = Request::delete(&format!("{}/projects/{}", API_ROOT, project_id))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
+    client().delete::<IgnoredAny>(&format!("/projects/{}", project_id)).await?;
     Ok(())
 }
 
-pub async fn get_project_details(project_id: i32) -> Result<ProjectDetails, String> 
+pub async fn get_project_details(project_id: i32) -> Result<ProjectDetails, ApiError> 
 {
-    let response = Request::get(&format!("{}/projects/{}", API_ROOT, project_id))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
-        .json::<ProjectDetailsResponse>()
+    client()
+        .get::<ProjectDetailsResponse>(&format!("/projects/{}", project_id))
         .await
         .map(|r| r.project)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
-pub async fn get_project_status(project_id: i32) -> Result<Option<String>, String> 
+pub async fn get_project_status(project_id: i32) -> Result<ContainerStatus, ApiError> 
 {
-    let response = Request::get(&format!("{}/projects/{}/status", API_ROOT, project_id))
-        .send()
+    client()
+        .get::<StatusResponse>(&format!("/projects/{}/status", project_id))
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
-        .json::<StatusResponse>()
-        .await
-        .map(|r| r.status)
-        .map_err(|e| format!("Failed to parse response: {}", e))
+        .map(|r| r.status.unwrap_or_default())
 }
 
-pub async fn start_project(project_id: i32) -> Result<(), String> 
+pub async fn start_project(project_id: i32) -> Result<(), ApiError> 
 {
-    let response = Request::post(&format!("{}/projects/{}/start", API_ROOT, project_id))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
+    client().post::<IgnoredAny>(&format!("/projects/{}/start", project_id)).await?;
+    Ok(())
+}
 
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
+pub async fn stop_project(project_id: i32) -> Result<(), ApiError> 
+{
+    client().post::<IgnoredAny>(&format!("/projects/{}/stop", project_id)).await?;
     Ok(())
 }
 
-pub async fn stop_project(project_id: i32) -> Result<(), String> 
+pub async fn restart_project(project_id: i32) -> Result<(), ApiError> 
 {
-    let response = Request::post(&format!("{}/projects/{}/stop", API_ROOT, project_id))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
+    client().post::<IgnoredAny>(&format!("/projects/{}/restart", project_id)).await?;
+    Ok(())
+}
 
-    if !response.ok() 
+fn log_query_params(query: &LogQuery) -> Vec<(&'static str, String)> 
+{
+    let mut params = Vec::new();
+    if let Some(tail) = query.tail 
     {
-        return Err(parse_simple_error_response(response).await);
+        params.push(("tail", tail.to_string()));
     }
-    Ok(())
+    if let Some(since) = query.since 
+    {
+        params.push(("since", since.to_string()));
+    }
+    if let Some(until) = query.until 
+    {
+        params.push(("until", until.to_string()));
+    }
+    params
 }
 
-pub async fn restart_project(project_id: i32) -> Result<(), String> 
+pub async fn get_project_logs(project_id: i32, query: &LogQuery) -> Result<String, ApiError> 
 {
-    let response = Request::post(&format!("{}/projects/{}/restart", API_ROOT, project_id))
-        .send()
+    client()
+        .get_with_query::<LogsResponse, _>(&format!("/projects/{}/logs", project_id), log_query_params(query))
         .await
-        .map_err(|e| format!("Network error: {}", e))?;
+        .map(|r| r.logs)
+}
 
-    if !response.ok() 
+/// Data of each message an event-stream endpoint sends. The stream ends when
+/// the connection drops.
+#[cfg(not(feature = "mock-api"))]
+fn event_stream(path: &str) -> Result<LocalBoxStream<'static, String>, ApiError> 
+{
+    let mut source = EventSource::new(&client().url(path))
+        .map_err(|e| ApiError::new(ErrorCode::NetworkError, Some(e.to_string())))?;
+    let messages = source
+        .subscribe("message")
+        .map_err(|e| ApiError::new(ErrorCode::NetworkError, Some(e.to_string())))?;
+
+    // The connection closes when `source` is dropped, so it is carried along
+    // with the subscription
+    let data = stream::unfold((source, messages), |(source, mut messages)| async move 
     {
-        return Err(parse_simple_error_response(response).await);
-    }
-    Ok(())
+        match messages.next().await 
+        {
+            Some(Ok((_, event))) => Some((event.data().as_string().unwrap_or_default(), (source, messages))),
+            _ => None,
+        }
+    });
+
+    Ok(data.boxed_local())
 }
 
-pub async fn get_project_logs(project_id: i32) -> Result<String, String> 
+/// Log lines pushed by the server as they are written. The stream ends when
+/// the connection drops.
+#[cfg(not(feature = "mock-api"))]
+pub fn stream_project_logs(project_id: i32) -> Result<LocalBoxStream<'static, String>, ApiError> 
 {
-    let response = Request::get(&format!("{}/projects/{}/logs", API_ROOT, project_id))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
+    event_stream(&format!("/projects/{}/logs/stream", project_id))
+}
 
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
+#[cfg(feature = "mock-api")]
+pub fn stream_project_logs(project_id: i32) -> Result<LocalBoxStream<'static, String>, ApiError> 
+{
+    Ok(mock_api::log_stream(project_id))
+}
 
-    response
-        .json::<LogsResponse>()
-        .await
-        .map(|r| r.logs)
-        .map_err(|e| format!("Failed to parse response: {}", e))
+/// Status changes of the given projects, pushed by the server. Malformed
+/// messages are skipped; the stream ends when the connection drops.
+#[cfg(not(feature = "mock-api"))]
+pub fn stream_project_statuses(project_ids: &[i32]) -> Result<LocalBoxStream<'static, StatusEvent>, ApiError> 
+{
+    let ids: Vec<String> = project_ids.iter().map(i32::to_string).collect();
+    let events = event_stream(&format!("/projects/status/stream?ids={}", ids.join(",")))?
+        .filter_map(|data| future::ready(serde_json::from_str::<StatusEvent>(&data).ok()));
+
+    Ok(events.boxed_local())
 }
 
-pub async fn get_project_metrics(project_id: i32) -> Result<ProjectMetrics, String> 
+#[cfg(feature = "mock-api")]
+pub fn stream_project_statuses(project_ids: &[i32]) -> Result<LocalBoxStream<'static, StatusEvent>, ApiError> 
 {
-    let response = Request::get(&format!("{}/projects/{}/metrics", API_ROOT, project_id))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
+    Ok(mock_api::status_stream(project_ids.to_vec()))
+}
 
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
+pub async fn get_project_metrics(project_id: i32) -> Result<ProjectMetrics, ApiError> 
+{
+    client().get(&format!("/projects/{}/metrics", project_id)).await
+}
 
-    response
-        .json::<ProjectMetrics>()
+pub async fn get_project_metrics_history(project_id: i32, range: MetricsHistoryRange) -> Result<Vec<MetricsSample>, ApiError> 
+{
+    client()
+        .get_with_query::<MetricsHistoryResponse, _>(&format!("/projects/{}/metrics/history", project_id), [("range", range.as_query())])
         .await
-        .map_err(|e| format!("Failed to parse response: {}", e))
+        .map(|r| r.samples)
 }
 
 pub async fn update_project_image(project_id: i32, new_image_url: &str) -> Result<(), ApiError> 
@@ -281,45 +215,13 @@ pub async fn update_project_image(project_id: i32, new_image_url: &str) -> Resul
         new_image_url: new_image_url.to_string(),
     };
 
-    let response = Request::put(&format!("{}/projects/{}/image", API_ROOT, project_id))
-        .json(&payload)
-        .map_err(|_| ApiError 
-        {
-            error_code: "CLIENT_SERIALIZATION_ERROR".to_string(),
-            details: None,
-        })?
-        .send()
-        .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
-
-    if !response.ok() 
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
-
+    build_client().put_json::<_, IgnoredAny>(&format!("/projects/{}/image", project_id), &payload).await?;
     Ok(())
 }
 
 pub async fn rebuild_project(project_id: i32) -> Result<(), ApiError>
 {
-    let response = Request::put(&format!("{}/projects/{}/rebuild", API_ROOT, project_id))
-        .send()
-        .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
-
-    if !response.ok()
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
-
+    build_client().put::<IgnoredAny>(&format!("/projects/{}/rebuild", project_id)).await?;
     Ok(())
 }
 
@@ -329,108 +231,42 @@ pub async fn add_participant(project_id: i32, participant_id: &str) -> Result<()
     {
         participant_id: participant_id.to_string(),
     };
-    let response = Request::post(&format!("{}/projects/{}/participants", API_ROOT, project_id))
-        .json(&payload)
-        .map_err(|_| ApiError 
-        {
-            error_code: "CLIENT_ERROR".to_string(),
-            details: None,
-        })?
-        .send()
-        .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
 
-    if !response.ok() 
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
+    client().post_json::<_, IgnoredAny>(&format!("/projects/{}/participants", project_id), &payload).await?;
     Ok(())
 }
 
-pub async fn remove_participant(project_id: i32, participant_id: &str) -> Result<(), String> 
+pub async fn remove_participant(project_id: i32, participant_id: &str) -> Result<(), ApiError> 
 {
-    let response = Request::delete(&format!(
-        "{}/projects/{}/participants/{}",
-        API_ROOT, project_id, participant_id
-    ))
-    .send()
-    .await
-    .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
+    client()
+        .delete::<IgnoredAny>(&format!("/projects/{}/participants/{}", project_id, participant_id))
+        .await?;
     Ok(())
 }
 
-pub async fn get_all_projects_admin() -> Result<Vec<Project>, String> 
+pub async fn get_all_projects_admin() -> Result<Vec<Project>, ApiError> 
 {
-    let response = Request::get(&format!("{}/admin/projects", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?;
-
-    if !response.ok() 
-    {
-        return Err(parse_simple_error_response(response).await);
-    }
-
-    response
-        .json::<ProjectsResponse>()
+    client()
+        .get::<ProjectsResponse>("/admin/projects")
         .await
         .map(|r| r.projects)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
-pub async fn get_global_metrics_admin() -> Result<GlobalMetrics, String> 
+pub async fn get_global_metrics_admin() -> Result<GlobalMetrics, ApiError> 
 {
-    Request::get(&format!("{}/admin/metrics", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?
-        .json::<GlobalMetrics>()
-        .await
-        .map_err(|e| format!("Failed to parse response: {}", e))
+    client().get("/admin/metrics").await
 }
 
-pub async fn get_down_projects_admin() -> Result<Vec<DownProjectInfo>, String> 
+pub async fn get_down_projects_admin() -> Result<Vec<DownProjectInfo>, ApiError> 
 {
-    Request::get(&format!("{}/admin/projects/down", API_ROOT))
-        .send()
-        .await
-        .map_err(|e| format!("Network error: {}", e))?
-        .json::<DownProjectsResponse>()
+    client()
+        .get::<DownProjectsResponse>("/admin/projects/down")
         .await
         .map(|r| r.down_projects)
-        .map_err(|e| format!("Failed to parse response: {}", e))
 }
 
 pub async fn update_env_vars(project_id: i32, payload: &UpdateEnvPayload) -> Result<(), ApiError>
 {
-    let response = Request::put(&format!("{}/projects/{}/env", API_ROOT, project_id))
-        .json(payload)
-        .map_err(|_| ApiError 
-        {
-            error_code: "CLIENT_ERROR".to_string(),
-            details: None,
-        })?
-        .send()
-        .await
-        .map_err(|e| ApiError 
-        {
-            error_code: "NETWORK_ERROR".to_string(),
-            details: Some(e.to_string()),
-        })?;
-
-    if !response.ok()
-    {
-        return Err(parse_detailed_error_response(response).await);
-    }
-
+    client().put_json::<_, IgnoredAny>(&format!("/projects/{}/env", project_id), payload).await?;
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,412 @@
+use std::borrow::Cow;
+
+const ESCAPE: char = '\x1b';
+
+const BASE_PALETTE: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00),
+    (0xcd, 0x31, 0x31),
+    (0x0d, 0xbc, 0x79),
+    (0xe5, 0xe5, 0x10),
+    (0x24, 0x72, 0xc8),
+    (0xbc, 0x3f, 0xbc),
+    (0x11, 0xa8, 0xcd),
+    (0xe5, 0xe5, 0xe5),
+    (0x66, 0x66, 0x66),
+    (0xf1, 0x4c, 0x4c),
+    (0x23, 0xd1, 0x8b),
+    (0xf5, 0xf5, 0x43),
+    (0x3b, 0x8e, 0xea),
+    (0xd6, 0x70, 0xd6),
+    (0x29, 0xb8, 0xdb),
+    (0xff, 0xff, 0xff),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnsiColor
+{
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor
+{
+    pub fn to_rgb(self) -> (u8, u8, u8)
+    {
+        match self
+        {
+            AnsiColor::Rgb(r, g, b) => (r, g, b),
+            AnsiColor::Indexed(index @ 0..=15) => BASE_PALETTE[index as usize],
+            AnsiColor::Indexed(index @ 16..=231) =>
+            {
+                let cube = index - 16;
+                let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+                (level(cube / 36), level((cube / 6) % 6), level(cube % 6))
+            }
+            AnsiColor::Indexed(index) =>
+            {
+                let gray = 8 + (index - 232) * 10;
+                (gray, gray, gray)
+            }
+        }
+    }
+
+    pub fn to_css(self) -> String
+    {
+        let (r, g, b) = self.to_rgb();
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AnsiStyle
+{
+    pub foreground: Option<AnsiColor>,
+    pub background: Option<AnsiColor>,
+    pub bold: bool,
+    pub dim: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl AnsiStyle
+{
+    pub fn is_plain(&self) -> bool
+    {
+        *self == AnsiStyle::default()
+    }
+
+    pub fn to_css(&self) -> String
+    {
+        let mut declarations = Vec::new();
+        if let Some(color) = self.foreground
+        {
+            declarations.push(format!("color: {}", color.to_css()));
+        }
+        if let Some(color) = self.background
+        {
+            declarations.push(format!("background-color: {}", color.to_css()));
+        }
+        if self.bold
+        {
+            declarations.push("font-weight: 700".to_string());
+        }
+        if self.dim
+        {
+            declarations.push("opacity: 0.7".to_string());
+        }
+        if self.italic
+        {
+            declarations.push("font-style: italic".to_string());
+        }
+        if self.underline
+        {
+            declarations.push("text-decoration: underline".to_string());
+        }
+        declarations.join("; ")
+    }
+
+    fn apply_sgr(&mut self, params: &[u16])
+    {
+        if params.is_empty()
+        {
+            *self = AnsiStyle::default();
+            return;
+        }
+
+        let mut params = params.iter().copied();
+        while let Some(code) = params.next()
+        {
+            match code
+            {
+                0 => *self = AnsiStyle::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 =>
+                {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                30..=37 => self.foreground = Some(AnsiColor::Indexed((code - 30) as u8)),
+                38 => self.foreground = parse_extended_color(&mut params),
+                39 => self.foreground = None,
+                40..=47 => self.background = Some(AnsiColor::Indexed((code - 40) as u8)),
+                48 => self.background = parse_extended_color(&mut params),
+                49 => self.background = None,
+                90..=97 => self.foreground = Some(AnsiColor::Indexed((code - 90 + 8) as u8)),
+                100..=107 => self.background = Some(AnsiColor::Indexed((code - 100 + 8) as u8)),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn parse_extended_color(params: &mut impl Iterator<Item = u16>) -> Option<AnsiColor>
+{
+    let channel = |value: Option<u16>| value.map(|v| v.min(255) as u8);
+
+    match params.next()
+    {
+        Some(5) => channel(params.next()).map(AnsiColor::Indexed),
+        Some(2) =>
+        {
+            let (r, g, b) = (channel(params.next())?, channel(params.next())?, channel(params.next())?);
+            Some(AnsiColor::Rgb(r, g, b))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnsiSegment<'a>
+{
+    pub text: &'a str,
+    pub style: AnsiStyle,
+}
+
+enum Token<'a>
+{
+    Text(&'a str),
+    Sgr(Vec<u16>),
+    Ignored,
+}
+
+/// Splits `input` into text and escape sequences. Only SGR sequences
+/// (`ESC [ ... m`) carry meaning; every other CSI, OSC or two-byte escape
+/// such as cursor movement is reported as `Token::Ignored`.
+fn tokenize(input: &str) -> Vec<Token<'_>>
+{
+    let mut tokens = Vec::new();
+    let mut rest = input;
+
+    while !rest.is_empty()
+    {
+        let Some(escape_at) = rest.find(ESCAPE)
+        else
+        {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if escape_at > 0
+        {
+            tokens.push(Token::Text(&rest[..escape_at]));
+        }
+        let sequence = &rest[escape_at + 1..];
+
+        let consumed = match sequence.chars().next()
+        {
+            Some('[') =>
+            {
+                let body = &sequence[1..];
+                match body.find(|c: char| ('\x40'..='\x7e').contains(&c))
+                {
+                    Some(final_at) =>
+                    {
+                        if body[final_at..].starts_with('m')
+                        {
+                            let raw_params = &body[..final_at];
+                            let params = if raw_params.is_empty()
+                            {
+                                Vec::new()
+                            }
+                            else
+                            {
+                                raw_params
+                                    .split([';', ':'])
+                                    .map(|p| p.parse::<u16>().unwrap_or(0))
+                                    .collect()
+                            };
+                            tokens.push(Token::Sgr(params));
+                        }
+                        else
+                        {
+                            tokens.push(Token::Ignored);
+                        }
+                        1 + final_at + 1
+                    }
+                    None =>
+                    {
+                        tokens.push(Token::Ignored);
+                        sequence.len()
+                    }
+                }
+            }
+            Some(']') =>
+            {
+                tokens.push(Token::Ignored);
+                let bell = sequence.find('\x07').map(|at| at + 1);
+                let terminator = sequence.find("\x1b\\").map(|at| at + 2);
+                match (bell, terminator)
+                {
+                    (Some(a), Some(b)) => a.min(b),
+                    (Some(end), None) | (None, Some(end)) => end,
+                    (None, None) => sequence.len(),
+                }
+            }
+            Some(c) =>
+            {
+                tokens.push(Token::Ignored);
+                c.len_utf8()
+            }
+            None =>
+            {
+                tokens.push(Token::Ignored);
+                0
+            }
+        };
+
+        rest = &sequence[consumed..];
+    }
+
+    tokens
+}
+
+/// Parses SGR escape sequences into styled segments, dropping every other
+/// control sequence.
+pub fn parse_ansi(input: &str) -> Vec<AnsiSegment<'_>>
+{
+    let mut segments: Vec<AnsiSegment> = Vec::new();
+    let mut style = AnsiStyle::default();
+
+    for token in tokenize(input)
+    {
+        match token
+        {
+            Token::Text(text) => segments.push(AnsiSegment { text, style: style.clone() }),
+            Token::Sgr(params) => style.apply_sgr(&params),
+            Token::Ignored => {}
+        }
+    }
+
+    segments
+}
+
+pub fn strip_ansi(input: &str) -> Cow<'_, str>
+{
+    if !input.contains(ESCAPE)
+    {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(parse_ansi(input).into_iter().map(|segment| segment.text).collect())
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn styled(text: &str, style: AnsiStyle) -> AnsiSegment<'_>
+    {
+        AnsiSegment { text, style }
+    }
+
+    #[test]
+    fn plain_text_is_a_single_unstyled_segment()
+    {
+        assert_eq!(parse_ansi("hello world"), vec![styled("hello world", AnsiStyle::default())]);
+        assert!(matches!(strip_ansi("hello world"), Cow::Borrowed("hello world")));
+    }
+
+    #[test]
+    fn foreground_color_and_reset()
+    {
+        let red = AnsiStyle { foreground: Some(AnsiColor::Indexed(1)), ..Default::default() };
+        assert_eq!(
+            parse_ansi("\x1b[31mfailed\x1b[0m done"),
+            vec![styled("failed", red), styled(" done", AnsiStyle::default())]
+        );
+    }
+
+    #[test]
+    fn combined_bold_underline_and_bright_color()
+    {
+        let style = AnsiStyle
+        {
+            foreground: Some(AnsiColor::Indexed(10)),
+            bold: true,
+            underline: true,
+            ..Default::default()
+        };
+        assert_eq!(parse_ansi("\x1b[1;4;92mOK"), vec![styled("OK", style)]);
+    }
+
+    #[test]
+    fn attributes_can_be_turned_off_individually()
+    {
+        let segments = parse_ansi("\x1b[1;3;4;44ma\x1b[22mb\x1b[23;24mc\x1b[49md");
+        let background = Some(AnsiColor::Indexed(4));
+
+        assert_eq!(segments[0].style, AnsiStyle { background, bold: true, italic: true, underline: true, ..Default::default() });
+        assert_eq!(segments[1].style, AnsiStyle { background, italic: true, underline: true, ..Default::default() });
+        assert_eq!(segments[2].style, AnsiStyle { background, ..Default::default() });
+        assert_eq!(segments[3].style, AnsiStyle::default());
+    }
+
+    #[test]
+    fn extended_256_and_truecolor()
+    {
+        let segments = parse_ansi("\x1b[38;5;208morange\x1b[48;2;10;20;30mbg\x1b[39mdefault");
+
+        assert_eq!(segments[0].style.foreground, Some(AnsiColor::Indexed(208)));
+        assert_eq!(segments[1].style.background, Some(AnsiColor::Rgb(10, 20, 30)));
+        assert_eq!(segments[2].style.foreground, None);
+        assert_eq!(segments[2].style.background, Some(AnsiColor::Rgb(10, 20, 30)));
+    }
+
+    #[test]
+    fn empty_sgr_resets_style()
+    {
+        let segments = parse_ansi("\x1b[1mbold\x1b[mplain");
+        assert_eq!(segments[1], styled("plain", AnsiStyle::default()));
+    }
+
+    #[test]
+    fn cursor_movement_and_erase_codes_are_stripped()
+    {
+        assert_eq!(strip_ansi("\x1b[2K\x1b[1GDownloading 100%\x1b[?25h"), "Downloading 100%");
+        assert_eq!(strip_ansi("\x1b[3A\x1b[10;20Hmoved\x1b7\x1b8"), "moved");
+    }
+
+    #[test]
+    fn osc_sequences_are_stripped()
+    {
+        assert_eq!(strip_ansi("\x1b]0;window title\x07text"), "text");
+        assert_eq!(strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), "link");
+    }
+
+    #[test]
+    fn unterminated_sequence_is_dropped()
+    {
+        assert_eq!(strip_ansi("tail\x1b[31"), "tail");
+        assert_eq!(strip_ansi("tail\x1b"), "tail");
+    }
+
+    #[test]
+    fn palette_conversion()
+    {
+        assert_eq!(AnsiColor::Indexed(1).to_css(), "#cd3131");
+        assert_eq!(AnsiColor::Indexed(16).to_rgb(), (0, 0, 0));
+        assert_eq!(AnsiColor::Indexed(231).to_rgb(), (255, 255, 255));
+        assert_eq!(AnsiColor::Indexed(232).to_rgb(), (8, 8, 8));
+        assert_eq!(AnsiColor::Rgb(255, 128, 0).to_css(), "#ff8000");
+    }
+
+    #[test]
+    fn style_to_css()
+    {
+        let style = AnsiStyle
+        {
+            foreground: Some(AnsiColor::Indexed(3)),
+            bold: true,
+            underline: true,
+            ..Default::default()
+        };
+        assert_eq!(style.to_css(), "color: #e5e510; font-weight: 700; text-decoration: underline");
+        assert!(AnsiStyle::default().is_plain());
+    }
+}
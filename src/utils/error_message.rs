@@ -0,0 +1,12 @@
+use crate::services::api_client::ApiError;
+
+/// Message shown for `error`, with when to try again if the server said.
+pub fn translate_error(error: &ApiError, i18n: &i18nrs::I18n) -> String
+{
+    let message = i18n.t(&error.error_code.translation_key());
+    match error.retry_after
+    {
+        Some(seconds) => format!("{} {}", message, i18n.t("errors.RETRY_IN").replace("{seconds}", &seconds.to_string())),
+        None => message,
+    }
+}
@@ -0,0 +1,333 @@
+use serde_json::{Map, Value};
+
+use crate::utils::ansi::strip_ansi;
+
+const LEVEL_KEYS: [&str; 4] = ["level", "lvl", "severity", "log.level"];
+const MESSAGE_KEYS: [&str; 2] = ["msg", "message"];
+const TIME_KEYS: [&str; 4] = ["time", "timestamp", "ts", "@timestamp"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogLevel
+{
+    Error,
+    Warn,
+    Info,
+}
+
+impl LogLevel
+{
+    pub fn css_class(self) -> &'static str
+    {
+        match self
+        {
+            LogLevel::Error => "log-error",
+            LogLevel::Warn => "log-warn",
+            LogLevel::Info => "log-info",
+        }
+    }
+
+    pub fn as_str(self) -> &'static str
+    {
+        match self
+        {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+        }
+    }
+
+    /// Reads a level field, either named (`"warning"`, `"ERR"`, ...) or
+    /// numeric as written by pino and bunyan (40 = warn, 50+ = error).
+    fn from_field(value: &str) -> Option<Self>
+    {
+        if let Ok(number) = value.parse::<f64>()
+        {
+            return Some(match number
+            {
+                n if n >= 50.0 => LogLevel::Error,
+                n if n >= 40.0 => LogLevel::Warn,
+                _ => LogLevel::Info,
+            });
+        }
+
+        match value.to_lowercase().as_str()
+        {
+            "error" | "err" | "fatal" | "panic" | "critical" | "crit" | "alert" | "emerg" | "emergency" => Some(LogLevel::Error),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "info" | "notice" | "debug" | "trace" | "verbose" => Some(LogLevel::Info),
+            _ => None,
+        }
+    }
+
+    fn from_text(message: &str) -> Self
+    {
+        let message_upper = message.to_uppercase();
+        if message_upper.contains("ERROR") || message_upper.contains("FAILED")
+        {
+            LogLevel::Error
+        }
+        else if message_upper.contains("WARN")
+        {
+            LogLevel::Warn
+        }
+        else
+        {
+            LogLevel::Info
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogEntry
+{
+    pub raw: String,
+    pub timestamp: String,
+    pub message: String,
+    pub level: LogLevel,
+    pub fields: Vec<(String, String)>,
+}
+
+fn split_docker_timestamp(line: &str) -> (&str, &str)
+{
+    match line.split_once(' ')
+    {
+        Some((timestamp, message)) if timestamp.ends_with('Z') => (timestamp, message.trim()),
+        _ => ("", line),
+    }
+}
+
+fn take_field(fields: &mut Vec<(String, String)>, keys: &[&str]) -> Option<String>
+{
+    let position = fields.iter().position(|(key, _)| keys.contains(&key.to_lowercase().as_str()))?;
+    Some(fields.remove(position).1)
+}
+
+fn parse_json_fields(message: &str) -> Option<Vec<(String, String)>>
+{
+    let message = message.trim();
+    if !message.starts_with('{')
+    {
+        return None;
+    }
+
+    let object = serde_json::from_str::<Map<String, Value>>(message).ok()?;
+    Some(
+        object
+            .into_iter()
+            .map(|(key, value)| match value
+            {
+                Value::String(text) => (key, text),
+                other => (key, other.to_string()),
+            })
+            .collect(),
+    )
+}
+
+fn is_logfmt_key_char(c: char) -> bool
+{
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | '@')
+}
+
+/// Parses `key=value key2="quoted value"` lines. Every token must be a
+/// key/value pair and one of them must be a level or message field, so
+/// free-form text containing a stray `=` is not mistaken for logfmt.
+fn parse_logfmt_fields(message: &str) -> Option<Vec<(String, String)>>
+{
+    let mut fields = Vec::new();
+    let mut chars = message.trim().chars().peekable();
+
+    loop
+    {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none()
+        {
+            break;
+        }
+
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|c| is_logfmt_key_char(*c))
+        {
+            key.push(c);
+        }
+        if key.is_empty() || chars.next() != Some('=')
+        {
+            return None;
+        }
+
+        let mut value = String::new();
+        if chars.next_if_eq(&'"').is_some()
+        {
+            loop
+            {
+                match chars.next()?
+                {
+                    '"' => break,
+                    '\\' => value.push(chars.next()?),
+                    c => value.push(c),
+                }
+            }
+        }
+        else
+        {
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace())
+            {
+                value.push(c);
+            }
+        }
+
+        fields.push((key, value));
+    }
+
+    let has_known_key = fields.iter().any(|(key, _)|
+    {
+        let key = key.to_lowercase();
+        LEVEL_KEYS.contains(&key.as_str()) || MESSAGE_KEYS.contains(&key.as_str())
+    });
+    has_known_key.then_some(fields)
+}
+
+pub fn parse_log_line(line: &str) -> LogEntry
+{
+    let (timestamp, message) = split_docker_timestamp(line);
+
+    let Some(mut fields) = parse_json_fields(message).or_else(|| parse_logfmt_fields(message))
+    else
+    {
+        return LogEntry
+        {
+            raw: line.to_string(),
+            timestamp: timestamp.to_string(),
+            message: message.to_string(),
+            level: LogLevel::from_text(&strip_ansi(message)),
+            fields: Vec::new(),
+        };
+    };
+
+    let level_field = take_field(&mut fields, &LEVEL_KEYS);
+    let structured_message = take_field(&mut fields, &MESSAGE_KEYS);
+    let structured_time = if timestamp.is_empty() { take_field(&mut fields, &TIME_KEYS) } else { None };
+
+    let message = structured_message.unwrap_or_else(|| message.to_string());
+    let level = level_field
+        .as_deref()
+        .and_then(LogLevel::from_field)
+        .unwrap_or_else(|| LogLevel::from_text(&strip_ansi(&message)));
+
+    LogEntry
+    {
+        raw: line.to_string(),
+        timestamp: structured_time.unwrap_or_else(|| timestamp.to_string()),
+        message,
+        level,
+        fields,
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn fields(pairs: &[(&str, &str)]) -> Vec<(String, String)>
+    {
+        pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn plain_line_keeps_docker_timestamp_and_guesses_level()
+    {
+        let entry = parse_log_line("2024-05-01T10:00:00.123Z Connection FAILED after 3 attempts");
+
+        assert_eq!(entry.timestamp, "2024-05-01T10:00:00.123Z");
+        assert_eq!(entry.message, "Connection FAILED after 3 attempts");
+        assert_eq!(entry.level, LogLevel::Error);
+        assert!(entry.fields.is_empty());
+    }
+
+    #[test]
+    fn text_level_fallback()
+    {
+        assert_eq!(LogLevel::from_text("WARNING: disk almost full"), LogLevel::Warn);
+        assert_eq!(LogLevel::from_text("error while reading"), LogLevel::Error);
+        assert_eq!(LogLevel::from_text("listening on :8080"), LogLevel::Info);
+        assert_eq!(parse_log_line("\x1b[31mERROR\x1b[0m boom").level, LogLevel::Error);
+    }
+
+    #[test]
+    fn json_line_extracts_level_message_and_fields()
+    {
+        let entry = parse_log_line(r#"2024-05-01T10:00:00Z {"level":"warn","msg":"slow query","ms":412,"table":"users"}"#);
+
+        assert_eq!(entry.timestamp, "2024-05-01T10:00:00Z");
+        assert_eq!(entry.message, "slow query");
+        assert_eq!(entry.level, LogLevel::Warn);
+        assert_eq!(entry.fields, fields(&[("ms", "412"), ("table", "users")]));
+    }
+
+    #[test]
+    fn json_time_field_is_used_without_docker_timestamp()
+    {
+        let entry = parse_log_line(r#"{"time":"2024-05-01T10:00:00Z","message":"started"}"#);
+
+        assert_eq!(entry.timestamp, "2024-05-01T10:00:00Z");
+        assert_eq!(entry.message, "started");
+        assert!(entry.fields.is_empty());
+    }
+
+    #[test]
+    fn numeric_pino_levels()
+    {
+        assert_eq!(LogLevel::from_field("20"), Some(LogLevel::Info));
+        assert_eq!(LogLevel::from_field("30"), Some(LogLevel::Info));
+        assert_eq!(LogLevel::from_field("40"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::from_field("50"), Some(LogLevel::Error));
+        assert_eq!(LogLevel::from_field("60"), Some(LogLevel::Error));
+        assert_eq!(parse_log_line(r#"{"level":50,"msg":"crashed"}"#).level, LogLevel::Error);
+    }
+
+    #[test]
+    fn named_levels_are_case_insensitive()
+    {
+        assert_eq!(LogLevel::from_field("WARNING"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::from_field("Fatal"), Some(LogLevel::Error));
+        assert_eq!(LogLevel::from_field("debug"), Some(LogLevel::Info));
+        assert_eq!(LogLevel::from_field("custom"), None);
+    }
+
+    #[test]
+    fn unknown_level_field_falls_back_to_message_text()
+    {
+        let entry = parse_log_line(r#"{"severity":"custom","msg":"upload failed"}"#);
+        assert_eq!(entry.level, LogLevel::Error);
+    }
+
+    #[test]
+    fn invalid_json_is_plain_text()
+    {
+        let entry = parse_log_line(r#"{"level":"error", truncated"#);
+
+        assert_eq!(entry.message, r#"{"level":"error", truncated"#);
+        assert!(entry.fields.is_empty());
+    }
+
+    #[test]
+    fn logfmt_line_with_quoted_and_escaped_values()
+    {
+        let entry = parse_log_line(r#"level=error msg="said \"no\" twice" host=db-1 path=/api/v1"#);
+
+        assert_eq!(entry.level, LogLevel::Error);
+        assert_eq!(entry.message, r#"said "no" twice"#);
+        assert_eq!(entry.fields, fields(&[("host", "db-1"), ("path", "/api/v1")]));
+    }
+
+    #[test]
+    fn stray_equals_in_plain_text_is_not_logfmt()
+    {
+        for line in ["Setting retries=5 for the run", "a=1 b=2", "level=info msg=\"unterminated", "=value msg=x"]
+        {
+            let entry = parse_log_line(line);
+            assert_eq!(entry.message, line, "{line}");
+            assert!(entry.fields.is_empty(), "{line}");
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod ansi;
+pub mod error_message;
+pub mod log_parser;
+pub mod query;
\ No newline at end of file
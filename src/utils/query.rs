@@ -0,0 +1,27 @@
+/// Reads a percent-encoded parameter from a `?a=1&b=2` query string.
+pub fn query_param(query: &str, name: &str) -> Option<String>
+{
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|p| p.strip_prefix(name)?.strip_prefix('='))
+        .and_then(|value| js_sys::decode_uri_component(value).ok())
+        .map(String::from)
+}
+
+/// Every parameter of a `?a=1&b=2` query string, decoded and in order.
+pub fn query_pairs(query: &str) -> Vec<(String, String)>
+{
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|p| !p.is_empty())
+        .filter_map(|p|
+        {
+            let (name, value) = p.split_once('=').unwrap_or((p, ""));
+            let name = js_sys::decode_uri_component(name).ok()?;
+            let value = js_sys::decode_uri_component(value).ok()?;
+            Some((String::from(name), String::from(value)))
+        })
+        .collect()
+}
@@ -0,0 +1,40 @@
+mod routes;
+mod state;
+
+use std::env;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use routes::Stub;
+use state::Fixtures;
+
+const DEFAULT_ADDR: &str = "127.0.0.1:3000";
+const DEFAULT_FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../fixtures");
+
+/// Stand-in for the Hangar backend serving `/api/*` from JSON fixtures,
+/// for the Trunk proxy and end-to-end tests.
+///
+/// `HANGAR_STUB_ADDR` and `HANGAR_STUB_FIXTURES` override the listen
+/// address and the fixture directory.
+#[tokio::main]
+async fn main()
+{
+    let addr: SocketAddr = env::var("HANGAR_STUB_ADDR")
+        .unwrap_or_else(|_| DEFAULT_ADDR.to_string())
+        .parse()
+        .expect("HANGAR_STUB_ADDR must be a socket address");
+    let fixtures_dir = env::var("HANGAR_STUB_FIXTURES")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_FIXTURES_DIR));
+
+    let fixtures = Fixtures::load(&fixtures_dir).unwrap_or_else(|e| panic!("Could not load fixtures: {}", e));
+    let stub = Arc::new(Mutex::new(Stub { fixtures_dir, fixtures }));
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .unwrap_or_else(|e| panic!("Could not bind {}: {}", addr, e));
+    println!("Hangar stub API listening on http://{}/api", addr);
+
+    axum::serve(listener, routes::router(stub)).await.expect("server error");
+}
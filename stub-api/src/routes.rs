@@ -0,0 +1,754 @@
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::sse::{Event, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{delete, get, post, put};
+use axum::{Json, Router};
+use futures::stream::{self, Stream, StreamExt};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::state::{iso_date, now_seconds, Database, Fixtures, Project, ProjectRecord, StubState};
+
+const LOG_STREAM_INTERVAL: Duration = Duration::from_secs(1);
+const STATUS_STREAM_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct Stub
+{
+    pub fixtures_dir: std::path::PathBuf,
+    pub fixtures: Fixtures,
+}
+
+pub type SharedStub = Arc<Mutex<Stub>>;
+
+/// Error answer in the backend's format. Without a code only the status is
+/// sent, like the backend does for framework-level failures.
+pub struct ApiError
+{
+    status: StatusCode,
+    code: Option<&'static str>,
+}
+
+fn fail(status: StatusCode, code: &'static str) -> ApiError
+{
+    ApiError { status, code: Some(code) }
+}
+
+fn fail_status(status: StatusCode) -> ApiError
+{
+    ApiError { status, code: None }
+}
+
+impl IntoResponse for ApiError
+{
+    fn into_response(self) -> Response
+    {
+        match self.code
+        {
+            Some(code) => (self.status, Json(json!({ "error_code": code, "details": null }))).into_response(),
+            None => self.status.into_response(),
+        }
+    }
+}
+
+type Reply = Result<Json<Value>, ApiError>;
+
+fn ok() -> Reply
+{
+    Ok(Json(Value::Null))
+}
+
+fn lock(stub: &SharedStub) -> MutexGuard<'_, Stub>
+{
+    stub.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn current_user(state: &StubState) -> Result<String, ApiError>
+{
+    state.current_user.clone().ok_or_else(|| fail(StatusCode::UNAUTHORIZED, "UNAUTHORIZED"))
+}
+
+/// Index of a project the current user may see, or may manage when
+/// `manage` is set (owner or admin).
+fn project_index(state: &StubState, id: i32, manage: bool) -> Result<usize, ApiError>
+{
+    let login = current_user(state)?;
+    let index = state
+        .projects
+        .iter()
+        .position(|p| p.project.id == id)
+        .ok_or_else(|| fail(StatusCode::NOT_FOUND, "NOT_FOUND"))?;
+
+    let record = &state.projects[index];
+    let is_manager = record.project.owner == login || state.is_admin(&login);
+    let is_viewer = is_manager || record.participants.contains(&login);
+
+    match (manage, is_manager, is_viewer)
+    {
+        (true, true, _) | (false, _, true) => Ok(index),
+        _ => Err(fail_status(StatusCode::FORBIDDEN)),
+    }
+}
+
+fn details_json(state: &StubState, record: &ProjectRecord) -> Value
+{
+    let mut details = json!(record.project);
+    details["participants"] = json!(record.participants);
+    details["database"] = record
+        .database_id
+        .and_then(|id| state.databases.iter().find(|db| db.id == id))
+        .map(|db| json!(db))
+        .unwrap_or(Value::Null);
+    details
+}
+
+fn projects_json<'a>(projects: impl Iterator<Item = &'a ProjectRecord>) -> Value
+{
+    let projects: Vec<&Project> = projects.map(|p| &p.project).collect();
+    json!({ "projects": projects })
+}
+
+fn set_status(record: &mut ProjectRecord, status: &str)
+{
+    record.stopped_at = match status
+    {
+        "exited" | "dead" => Some(now_seconds()),
+        _ => None,
+    };
+    record.status = status.to_string();
+}
+
+// ============================================================================
+// AUTH
+// ============================================================================
+
+#[derive(Deserialize)]
+struct TicketQuery
+{
+    ticket: String,
+}
+
+async fn me(State(stub): State<SharedStub>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    let login = current_user(state)?;
+    let user = state.user(&login).ok_or_else(|| fail(StatusCode::UNAUTHORIZED, "UNAUTHORIZED"))?;
+    Ok(Json(json!({ "user": user })))
+}
+
+/// Any ticket logs in: `ST-<login>` picks that user, anything else the
+/// first one in the fixtures.
+async fn callback(State(stub): State<SharedStub>, Query(query): Query<TicketQuery>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let user = query
+        .ticket
+        .strip_prefix("ST-")
+        .and_then(|login| state.user(login))
+        .or_else(|| state.users.first())
+        .cloned()
+        .ok_or_else(|| fail(StatusCode::UNAUTHORIZED, "UNAUTHORIZED"))?;
+
+    state.current_user = Some(user.login.clone());
+    Ok(Json(json!({ "user": user })))
+}
+
+async fn logout(State(stub): State<SharedStub>) -> Reply
+{
+    lock(&stub).fixtures.state.current_user = None;
+    ok()
+}
+
+// ============================================================================
+// PROJECTS
+// ============================================================================
+
+async fn owned_projects(State(stub): State<SharedStub>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    let login = current_user(state)?;
+    Ok(Json(projects_json(state.projects.iter().filter(|p| p.project.owner == login))))
+}
+
+async fn participating_projects(State(stub): State<SharedStub>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    let login = current_user(state)?;
+    Ok(Json(projects_json(state.projects.iter().filter(|p| p.participants.contains(&login)))))
+}
+
+#[derive(Deserialize)]
+struct DeployPayload
+{
+    project_name: String,
+    image_url: Option<String>,
+    github_repo_url: Option<String>,
+    github_branch: Option<String>,
+    github_root_dir: Option<String>,
+    #[serde(default)]
+    participants: Vec<String>,
+    env_vars: Option<BTreeMap<String, String>>,
+    persistent_volume_path: Option<String>,
+    create_database: Option<bool>,
+}
+
+fn project_name_is_valid(name: &str) -> bool
+{
+    (3..=40).contains(&name.len())
+        && name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+}
+
+fn new_database(state: &mut StubState, login: &str) -> Result<i32, ApiError>
+{
+    if state.databases.iter().any(|db| db.owner == login)
+    {
+        return Err(fail(StatusCode::CONFLICT, "DATABASE_ALREADY_EXISTS"));
+    }
+
+    let id = state.next_id();
+    state.databases.push(Database
+    {
+        id,
+        database_name: format!("{}_db", login),
+        username: login.to_string(),
+        password: "stub-password".to_string(),
+        host: "localhost".to_string(),
+        port: 5432,
+        project_id: None,
+        owner: login.to_string(),
+    });
+    Ok(id)
+}
+
+async fn deploy(State(stub): State<SharedStub>, Json(payload): Json<DeployPayload>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let login = current_user(state)?;
+
+    if !project_name_is_valid(&payload.project_name)
+    {
+        return Err(fail(StatusCode::BAD_REQUEST, "INVALID_PROJECT_NAME"));
+    }
+    if state.projects.iter().any(|p| p.project.name == payload.project_name)
+    {
+        return Err(fail(StatusCode::CONFLICT, "PROJECT_NAME_TAKEN"));
+    }
+    if payload.participants.contains(&login)
+    {
+        return Err(fail(StatusCode::BAD_REQUEST, "OWNER_CANNOT_BE_PARTICIPANT"));
+    }
+
+    let (source, source_url) = match (payload.image_url, payload.github_repo_url)
+    {
+        (Some(image), _) if image.trim().is_empty() || image.contains(char::is_whitespace) =>
+        {
+            return Err(fail(StatusCode::BAD_REQUEST, "INVALID_IMAGE_URL"));
+        }
+        (Some(image), _) => ("direct", image),
+        (None, Some(repo)) => ("github", repo),
+        (None, None) => return Err(fail(StatusCode::BAD_REQUEST, "CLIENT_ERROR")),
+    };
+
+    let database_id = match payload.create_database
+    {
+        Some(true) => Some(new_database(state, &login)?),
+        _ => None,
+    };
+
+    let id = state.next_id();
+    let record = ProjectRecord
+    {
+        project: Project
+        {
+            id,
+            name: payload.project_name.clone(),
+            owner: login,
+            container_name: format!("hangar-{}", payload.project_name),
+            source: source.to_string(),
+            deployed_image_tag: format!("{}:latest", payload.project_name),
+            source_url,
+            source_branch: payload.github_branch,
+            source_root_dir: payload.github_root_dir,
+            created_at: iso_date(now_seconds()),
+            env_vars: payload.env_vars,
+            persistent_volume_path: payload.persistent_volume_path,
+        },
+        participants: payload.participants,
+        status: "running".to_string(),
+        database_id,
+        downtime_seconds: None,
+        stopped_at: None,
+    };
+
+    if let Some(database) = state.databases.iter_mut().find(|db| Some(db.id) == database_id)
+    {
+        database.project_id = Some(id);
+    }
+
+    let details = details_json(state, &record);
+    state.projects.push(record);
+    Ok(Json(json!({ "project": details })))
+}
+
+async fn project_details(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    let index = project_index(state, id, false)?;
+    Ok(Json(json!({ "project": details_json(state, &state.projects[index]) })))
+}
+
+async fn purge_project(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    state.projects.remove(index);
+    state
+        .databases
+        .iter_mut()
+        .filter(|db| db.project_id == Some(id))
+        .for_each(|db| db.project_id = None);
+    ok()
+}
+
+async fn project_status(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    let index = project_index(state, id, false)?;
+    Ok(Json(json!({ "status": state.projects[index].status })))
+}
+
+/// start, stop and restart apply immediately so tests need not wait.
+fn control(stub: &SharedStub, id: i32, status: &str) -> Reply
+{
+    let mut stub = lock(stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, false)?;
+    set_status(&mut state.projects[index], status);
+    ok()
+}
+
+async fn start_project(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    control(&stub, id, "running")
+}
+
+async fn stop_project(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    control(&stub, id, "exited")
+}
+
+async fn restart_project(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    control(&stub, id, "running")
+}
+
+#[derive(Deserialize)]
+struct LogQuery
+{
+    tail: Option<usize>,
+    since: Option<u64>,
+    until: Option<u64>,
+}
+
+async fn project_logs(State(stub): State<SharedStub>, Path(id): Path<i32>, Query(query): Query<LogQuery>) -> Reply
+{
+    let stub = lock(&stub);
+    project_index(&stub.fixtures.state, id, false)?;
+
+    // Lines start with an ISO timestamp, which sorts like the time itself
+    let since = query.since.map(iso_date);
+    let until = query.until.map(iso_date);
+    let lines: Vec<&str> = stub
+        .fixtures
+        .logs
+        .iter()
+        .map(String::as_str)
+        .filter(|line| since.as_deref().is_none_or(|since| *line >= since))
+        .filter(|line| until.as_deref().is_none_or(|until| *line <= until))
+        .collect();
+    let skip = query.tail.map_or(0, |tail| lines.len().saturating_sub(tail));
+
+    Ok(Json(json!({ "logs": lines[skip..].join("\n") })))
+}
+
+/// Replays the fixture log lines one per `LOG_STREAM_INTERVAL`, forever.
+async fn project_log_stream(
+    State(stub): State<SharedStub>,
+    Path(id): Path<i32>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError>
+{
+    let lines = {
+        let stub = lock(&stub);
+        project_index(&stub.fixtures.state, id, false)?;
+        stub.fixtures.logs.clone()
+    };
+
+    let events = stream::unfold(0, move |position|
+    {
+        let lines = lines.clone();
+        async move
+        {
+            tokio::time::sleep(LOG_STREAM_INTERVAL).await;
+            let line = lines.get(position % lines.len().max(1)).cloned().unwrap_or_default();
+            Some((Ok(Event::default().data(line)), position + 1))
+        }
+    });
+
+    Ok(Sse::new(events))
+}
+
+#[derive(Deserialize)]
+struct StatusStreamQuery
+{
+    /// Comma-separated project ids.
+    ids: String,
+}
+
+/// Sends the status of every visible project in `ids` at once, then each
+/// change, checked every `STATUS_STREAM_INTERVAL`.
+async fn project_status_stream(
+    State(stub): State<SharedStub>,
+    Query(query): Query<StatusStreamQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError>
+{
+    let ids: Vec<i32> = query.ids.split(',').filter_map(|id| id.trim().parse().ok()).collect();
+    current_user(&lock(&stub).fixtures.state)?;
+
+    let events = stream::unfold((BTreeMap::new(), true), move |(mut sent, first)|
+    {
+        let stub = stub.clone();
+        let ids = ids.clone();
+        async move
+        {
+            if !first
+            {
+                tokio::time::sleep(STATUS_STREAM_INTERVAL).await;
+            }
+
+            let stub = lock(&stub);
+            let state = &stub.fixtures.state;
+            let changes: Vec<Result<Event, Infallible>> = ids
+                .iter()
+                .filter_map(|&id| project_index(state, id, false).ok())
+                .map(|index| &state.projects[index])
+                .filter(|record| sent.insert(record.project.id, record.status.clone()).as_ref() != Some(&record.status))
+                .map(|record| Ok(Event::default().data(json!({ "project_id": record.project.id, "status": record.status }).to_string())))
+                .collect();
+
+            Some((stream::iter(changes), (sent, false)))
+        }
+    })
+    .flatten();
+
+    Ok(Sse::new(events))
+}
+
+async fn project_metrics(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let stub = lock(&stub);
+    project_index(&stub.fixtures.state, id, false)?;
+    Ok(Json(stub.fixtures.metrics.clone()))
+}
+
+async fn project_metrics_history(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let stub = lock(&stub);
+    project_index(&stub.fixtures.state, id, false)?;
+    Ok(Json(stub.fixtures.metrics_history.clone()))
+}
+
+#[derive(Deserialize)]
+struct UpdateImagePayload
+{
+    new_image_url: String,
+}
+
+async fn update_image(State(stub): State<SharedStub>, Path(id): Path<i32>, Json(payload): Json<UpdateImagePayload>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    if payload.new_image_url.trim().is_empty() || payload.new_image_url.contains(char::is_whitespace)
+    {
+        return Err(fail(StatusCode::BAD_REQUEST, "INVALID_IMAGE_URL"));
+    }
+
+    let project = &mut state.projects[index].project;
+    project.source_url = payload.new_image_url.clone();
+    project.deployed_image_tag = payload.new_image_url;
+    ok()
+}
+
+async fn rebuild_project(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    set_status(&mut state.projects[index], "running");
+    ok()
+}
+
+#[derive(Deserialize)]
+struct UpdateEnvPayload
+{
+    env_vars: BTreeMap<String, String>,
+}
+
+async fn update_env(State(stub): State<SharedStub>, Path(id): Path<i32>, Json(payload): Json<UpdateEnvPayload>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    state.projects[index].project.env_vars = Some(payload.env_vars);
+    ok()
+}
+
+#[derive(Deserialize)]
+struct ParticipantPayload
+{
+    participant_id: String,
+}
+
+async fn add_participant(State(stub): State<SharedStub>, Path(id): Path<i32>, Json(payload): Json<ParticipantPayload>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    let record = &mut state.projects[index];
+
+    if payload.participant_id == record.project.owner
+    {
+        return Err(fail(StatusCode::BAD_REQUEST, "OWNER_CANNOT_BE_PARTICIPANT"));
+    }
+    if !record.participants.contains(&payload.participant_id)
+    {
+        record.participants.push(payload.participant_id);
+    }
+    ok()
+}
+
+async fn remove_participant(State(stub): State<SharedStub>, Path((id, participant)): Path<(i32, String)>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    state.projects[index].participants.retain(|p| *p != participant);
+    ok()
+}
+
+// ============================================================================
+// DATABASES
+// ============================================================================
+
+async fn my_database(State(stub): State<SharedStub>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    let login = current_user(state)?;
+    let database = state
+        .databases
+        .iter()
+        .find(|db| db.owner == login)
+        .ok_or_else(|| fail(StatusCode::NOT_FOUND, "NOT_FOUND"))?;
+    Ok(Json(json!({ "database": database })))
+}
+
+async fn create_database(State(stub): State<SharedStub>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let login = current_user(state)?;
+    let id = new_database(state, &login)?;
+    let database = state.databases.iter().find(|db| db.id == id);
+    Ok(Json(json!({ "database": database })))
+}
+
+async fn delete_database(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let login = current_user(state)?;
+    let position = state
+        .databases
+        .iter()
+        .position(|db| db.id == id && db.owner == login)
+        .ok_or_else(|| fail(StatusCode::NOT_FOUND, "NOT_FOUND"))?;
+
+    if state.databases[position].project_id.is_some()
+    {
+        return Err(fail(StatusCode::CONFLICT, "DELETE_FAILED"));
+    }
+    state.databases.remove(position);
+    ok()
+}
+
+async fn link_database(State(stub): State<SharedStub>, Path((id, db_id)): Path<(i32, i32)>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    let login = current_user(state)?;
+    let database = state
+        .databases
+        .iter_mut()
+        .find(|db| db.id == db_id && db.owner == login)
+        .ok_or_else(|| fail(StatusCode::NOT_FOUND, "NOT_FOUND"))?;
+
+    if database.project_id.is_some()
+    {
+        return Err(fail(StatusCode::CONFLICT, "LINK_FAILED"));
+    }
+    database.project_id = Some(id);
+    state.projects[index].database_id = Some(db_id);
+    ok()
+}
+
+async fn unlink_database(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    state.projects[index].database_id = None;
+    state
+        .databases
+        .iter_mut()
+        .filter(|db| db.project_id == Some(id))
+        .for_each(|db| db.project_id = None);
+    ok()
+}
+
+async fn delete_linked_database(State(stub): State<SharedStub>, Path(id): Path<i32>) -> Reply
+{
+    let mut stub = lock(&stub);
+    let state = &mut stub.fixtures.state;
+    let index = project_index(state, id, true)?;
+    let database_id = state.projects[index]
+        .database_id
+        .take()
+        .ok_or_else(|| fail(StatusCode::NOT_FOUND, "NOT_FOUND"))?;
+    state.databases.retain(|db| db.id != database_id);
+    ok()
+}
+
+// ============================================================================
+// ADMIN
+// ============================================================================
+
+fn require_admin(state: &StubState) -> Result<(), ApiError>
+{
+    let login = current_user(state)?;
+    if state.is_admin(&login) { Ok(()) } else { Err(fail_status(StatusCode::FORBIDDEN)) }
+}
+
+async fn admin_projects(State(stub): State<SharedStub>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    require_admin(state)?;
+    Ok(Json(projects_json(state.projects.iter())))
+}
+
+async fn admin_down_projects(State(stub): State<SharedStub>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    require_admin(state)?;
+
+    let now = now_seconds();
+    let down: Vec<Value> = state
+        .projects
+        .iter()
+        .filter_map(|record|
+        {
+            let stopped_at = record.stopped_at?;
+            let mut info = json!(record.project);
+            info["stopped_at"] = json!(iso_date(stopped_at));
+            info["downtime_seconds"] = json!(now.saturating_sub(stopped_at));
+            Some(info)
+        })
+        .collect();
+    Ok(Json(json!({ "down_projects": down })))
+}
+
+async fn admin_metrics(State(stub): State<SharedStub>) -> Reply
+{
+    let stub = lock(&stub);
+    let state = &stub.fixtures.state;
+    require_admin(state)?;
+
+    let running = state.projects.iter().filter(|p| p.status == "running").count();
+    let metric = |name: &str| stub.fixtures.metrics[name].as_f64().unwrap_or_default();
+    Ok(Json(json!({
+        "total_projects": state.projects.len(),
+        "running_containers": running,
+        "total_cpu_usage": metric("cpu_usage") * running as f64,
+        "total_memory_usage_mb": metric("memory_usage") * running as f64,
+    })))
+}
+
+// ============================================================================
+// STUB CONTROL
+// ============================================================================
+
+/// Reloads the fixtures from disk so every test starts from the same state.
+async fn reset(State(stub): State<SharedStub>) -> Result<StatusCode, (StatusCode, String)>
+{
+    let mut stub = lock(&stub);
+    stub.fixtures = Fixtures::load(&stub.fixtures_dir).map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub fn router(stub: SharedStub) -> Router
+{
+    let api = Router::new()
+        .route("/auth/me", get(me))
+        .route("/auth/callback", get(callback))
+        .route("/auth/logout", get(logout))
+        .route("/projects/owned", get(owned_projects))
+        .route("/projects/participations", get(participating_projects))
+        .route("/projects/deploy", post(deploy))
+        .route("/projects/status/stream", get(project_status_stream))
+        .route("/projects/{id}", get(project_details).delete(purge_project))
+        .route("/projects/{id}/status", get(project_status))
+        .route("/projects/{id}/start", post(start_project))
+        .route("/projects/{id}/stop", post(stop_project))
+        .route("/projects/{id}/restart", post(restart_project))
+        .route("/projects/{id}/logs", get(project_logs))
+        .route("/projects/{id}/logs/stream", get(project_log_stream))
+        .route("/projects/{id}/metrics", get(project_metrics))
+        .route("/projects/{id}/metrics/history", get(project_metrics_history))
+        .route("/projects/{id}/image", put(update_image))
+        .route("/projects/{id}/rebuild", put(rebuild_project))
+        .route("/projects/{id}/env", put(update_env))
+        .route("/projects/{id}/participants", post(add_participant))
+        .route("/projects/{id}/participants/{participant}", delete(remove_participant))
+        .route("/projects/{id}/database", delete(unlink_database))
+        .route("/projects/{id}/database/delete", delete(delete_linked_database))
+        .route("/projects/{id}/database/{db_id}", put(link_database))
+        .route("/databases", post(create_database))
+        .route("/databases/mine", get(my_database))
+        .route("/databases/{id}", delete(delete_database))
+        .route("/admin/projects", get(admin_projects))
+        .route("/admin/projects/down", get(admin_down_projects))
+        .route("/admin/metrics", get(admin_metrics));
+
+    Router::new()
+        .nest("/api", api)
+        .route("/__stub/reset", post(reset))
+        .with_state(stub)
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct User
+{
+    pub login: String,
+    pub name: String,
+    pub email: String,
+    pub is_admin: bool,
+}
+
+/// Same shape as the backend's project JSON.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Project
+{
+    pub id: i32,
+    pub name: String,
+    pub owner: String,
+    pub container_name: String,
+    pub source: String,
+    pub source_url: String,
+    pub source_branch: Option<String>,
+    pub source_root_dir: Option<String>,
+    pub deployed_image_tag: String,
+    pub created_at: String,
+    pub env_vars: Option<BTreeMap<String, String>>,
+    pub persistent_volume_path: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProjectRecord
+{
+    #[serde(flatten)]
+    pub project: Project,
+    #[serde(default)]
+    pub participants: Vec<String>,
+    pub status: String,
+    #[serde(default)]
+    pub database_id: Option<i32>,
+    /// For stopped projects, how long ago they went down.
+    #[serde(default)]
+    pub downtime_seconds: Option<u64>,
+    #[serde(skip)]
+    pub stopped_at: Option<u64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Database
+{
+    pub id: i32,
+    pub database_name: String,
+    pub username: String,
+    pub password: String,
+    pub host: String,
+    pub port: u16,
+    pub project_id: Option<i32>,
+    #[serde(skip_serializing)]
+    pub owner: String,
+}
+
+/// `state.json`: everything the stub can change.
+#[derive(Clone, Debug, Deserialize)]
+pub struct StubState
+{
+    pub current_user: Option<String>,
+    pub users: Vec<User>,
+    pub projects: Vec<ProjectRecord>,
+    pub databases: Vec<Database>,
+}
+
+/// Read-only answers served as recorded.
+#[derive(Clone, Debug)]
+pub struct Fixtures
+{
+    pub state: StubState,
+    pub logs: Vec<String>,
+    pub metrics: Value,
+    pub metrics_history: Value,
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(dir: &Path, name: &str) -> Result<T, String>
+{
+    let path = dir.join(name);
+    let text = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+impl Fixtures
+{
+    pub fn load(dir: &Path) -> Result<Self, String>
+    {
+        let logs_path = dir.join("logs.txt");
+        let logs = fs::read_to_string(&logs_path)
+            .map_err(|e| format!("{}: {}", logs_path.display(), e))?
+            .lines()
+            .map(String::from)
+            .collect();
+
+        let mut state: StubState = read_json(dir, "state.json")?;
+        let now = now_seconds();
+        for record in &mut state.projects
+        {
+            record.stopped_at = record.downtime_seconds.map(|downtime| now.saturating_sub(downtime));
+        }
+
+        Ok(Fixtures
+        {
+            state,
+            logs,
+            metrics: read_json(dir, "metrics.json")?,
+            metrics_history: read_json(dir, "metrics_history.json")?,
+        })
+    }
+}
+
+impl StubState
+{
+    pub fn user(&self, login: &str) -> Option<&User>
+    {
+        self.users.iter().find(|user| user.login == login)
+    }
+
+    pub fn is_admin(&self, login: &str) -> bool
+    {
+        self.user(login).is_some_and(|user| user.is_admin)
+    }
+
+    pub fn next_id(&self) -> i32
+    {
+        let project_ids = self.projects.iter().map(|p| p.project.id);
+        let database_ids = self.databases.iter().map(|db| db.id);
+        project_ids.chain(database_ids).max().unwrap_or(0) + 1
+    }
+}
+
+pub fn now_seconds() -> u64
+{
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// Formats Unix seconds as `YYYY-MM-DDTHH:MM:SSZ`, using the civil-from-days
+/// algorithm so the stub needs no date crate.
+pub fn iso_date(seconds: u64) -> String
+{
+    let days = (seconds / 86_400) as i64;
+    let time = seconds % 86_400;
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, time / 3_600, time % 3_600 / 60, time % 60)
+}